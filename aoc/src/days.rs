@@ -0,0 +1,26 @@
+use common::{Answer, ParseError};
+
+pub type Solver = fn(&[String]) -> Result<(Answer, Answer), ParseError>;
+
+pub fn solver(day: u32) -> Option<Solver> {
+    let solve: Solver = match day {
+        1 => day01_calorie_counting::solve,
+        2 => day02_rock_paper_scissors::solve,
+        3 => day03_rucksack_reorganization::solve,
+        4 => day04_camp_cleanup::solve,
+        5 => day05_supply_stacks::solve,
+        6 => day06_tuning_trouble::solve,
+        7 => day07_no_space_left_on_device::solve,
+        8 => day08_treetop_tree_house::solve,
+        9 => day09_rope_bridge::solve,
+        10 => day10_cathode_ray_tube::solve,
+        11 => day11_monkey_in_the_middle::solve,
+        12 => day12_hill_climbing_algorithm::solve,
+        13 => day13_distress_signal::solve,
+        14 => day14_regolith_reservoir::solve,
+        15 => day15_beacon_exclusion_zone::solve,
+        _ => return None,
+    };
+
+    Some(solve)
+}
@@ -0,0 +1,85 @@
+mod days;
+mod scaffold;
+
+use clap::{Parser, Subcommand};
+use common::{print_part, read_lines};
+use serde_json::json;
+use std::process;
+
+#[derive(Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day with its input read from stdin
+    Run {
+        day: u32,
+
+        /// Print the answers as a JSON object
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Generate a new day crate and register it with the workspace and runner
+    New {
+        day: u32,
+
+        /// Short name of the puzzle, e.g. `regolith_reservoir`
+        slug: String,
+
+        /// Depend on the shared grid (and geometry) crates
+        #[arg(long)]
+        grid: bool,
+
+        /// Depend on the shared geometry crate
+        #[arg(long)]
+        geometry: bool,
+    },
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Run { day, json } => run(day, json),
+        Command::New {
+            day,
+            slug,
+            grid,
+            geometry,
+        } => new_day(day, &slug, &scaffold::Options { grid, geometry }),
+    }
+}
+
+fn run(day: u32, json: bool) {
+    let Some(solve) = days::solver(day) else {
+        eprintln!("no solver for day {day}");
+        process::exit(1);
+    };
+
+    let lines = read_lines();
+    let (part_1, part_2) = solve(&lines).unwrap_or_else(|err| err.exit());
+
+    if json {
+        println!(
+            "{}",
+            json!({ "day": day, "part_1": part_1, "part_2": part_2 })
+        );
+    } else {
+        print_part(1, &part_1);
+        print_part(2, &part_2);
+    }
+}
+
+fn new_day(day: u32, slug: &str, options: &scaffold::Options) {
+    match scaffold::new_day(day, slug, options) {
+        Ok(dir) => println!("created {}", dir.display()),
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    fs,
+    io::{self, Error},
+    path::{Path, PathBuf},
+};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const FUZZ_TEMPLATE: &str = include_str!("../templates/fuzz_target.rs.tmpl");
+
+pub struct Options {
+    pub grid: bool,
+    pub geometry: bool,
+}
+
+// Creates `dayNN_slug` next to the other days, adds it to the workspace,
+// registers its solver with the runner and gives its parser a fuzz target.
+pub fn new_day(day: u32, slug: &str, options: &Options) -> io::Result<PathBuf> {
+    let root = workspace_root();
+    let name = crate_name(day, slug)?;
+    let dir = root.join(&name);
+
+    if dir.exists() {
+        return Err(Error::other(format!("{} already exists", dir.display())));
+    }
+
+    let days_rs = root.join("aoc/src/days.rs");
+    let days = fs::read_to_string(&days_rs)?;
+    if days.contains(&format!("        {day} => ")) {
+        return Err(Error::other(format!("day {day} is already registered")));
+    }
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), cargo_toml(&name, options))?;
+    fs::write(dir.join("src/lib.rs"), LIB_TEMPLATE)?;
+    fs::write(
+        dir.join("src/main.rs"),
+        MAIN_TEMPLATE.replace("{name}", &name),
+    )?;
+    fs::write(dir.join("example.txt"), "")?;
+    fs::write(dir.join("input.txt"), "")?;
+
+    let workspace = root.join("Cargo.toml");
+    let member = format!("    \"{name}\",");
+    insert_sorted(&workspace, &member, |line| line.starts_with("    \"day"))?;
+
+    let runner = root.join("aoc/Cargo.toml");
+    let dependency = format!("{name} = {{ path = \"../{name}\" }}");
+    insert_sorted(&runner, &dependency, |line| line.starts_with("day"))?;
+
+    let arm = format!("        {day} => {name}::solve,");
+    insert_sorted(&days_rs, &arm, |line| {
+        line.trim_start().starts_with(|c: char| c.is_ascii_digit())
+    })?;
+
+    add_fuzz_target(&root, day, &name)?;
+
+    Ok(dir)
+}
+
+fn add_fuzz_target(root: &Path, day: u32, name: &str) -> io::Result<()> {
+    let target = format!("day{day:02}");
+    fs::write(
+        root.join(format!("fuzz/fuzz_targets/{target}.rs")),
+        FUZZ_TEMPLATE.replace("{name}", name),
+    )?;
+
+    let manifest = root.join("fuzz/Cargo.toml");
+    let dependency = format!("{name} = {{ path = \"../{name}\" }}");
+    insert_sorted(&manifest, &dependency, |line| line.starts_with("day"))?;
+
+    let bin = format!(
+        "\n[[bin]]\nname = \"{target}\"\npath = \"fuzz_targets/{target}.rs\"\ntest = false\ndoc = false\nbench = false\n"
+    );
+    let mut content = fs::read_to_string(&manifest)?;
+    content.push_str(&bin);
+    fs::write(&manifest, content)
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner lives inside the workspace")
+        .to_path_buf()
+}
+
+fn crate_name(day: u32, slug: &str) -> io::Result<String> {
+    let slug = slug.replace('-', "_");
+    let valid = !slug.is_empty()
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if !(1..=25).contains(&day) || !valid {
+        return Err(Error::other(format!("invalid day or slug: {day} {slug}")));
+    }
+
+    Ok(format!("day{day:02}_{slug}"))
+}
+
+fn cargo_toml(name: &str, options: &Options) -> String {
+    let mut dependencies = vec![r#"common = { path = "../common" }"#];
+    if options.geometry || options.grid {
+        dependencies.push(r#"geometry = { path = "../geometry" }"#);
+    }
+    if options.grid {
+        dependencies.push(r#"grid = { path = "../grid" }"#);
+    }
+
+    CARGO_TEMPLATE
+        .replace("{name}", name)
+        .replace("{dependencies}", &dependencies.join("\n"))
+}
+
+// Inserts `entry` into the block of lines selected by `in_block`, keeping the
+// block sorted. Day numbers are zero padded in names but not in match arms, so
+// entries are compared by the number they start with when there is one.
+fn insert_sorted(path: &Path, entry: &str, in_block: impl Fn(&str) -> bool) -> io::Result<()> {
+    let content = fs::read_to_string(path)?;
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let block: Vec<usize> = (0..lines.len()).filter(|&i| in_block(lines[i])).collect();
+    let Some(&last) = block.last() else {
+        return Err(Error::other(format!(
+            "nowhere to register in {}",
+            path.display()
+        )));
+    };
+
+    let position = block
+        .iter()
+        .find(|&&i| sort_key(lines[i]) > sort_key(entry))
+        .copied()
+        .unwrap_or(last + 1);
+
+    lines.insert(position, entry);
+    fs::write(path, lines.join("\n") + "\n")
+}
+
+fn sort_key(line: &str) -> u32 {
+    line.trim_start_matches(|c: char| !c.is_ascii_digit())
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .unwrap_or(0)
+}
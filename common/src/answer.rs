@@ -0,0 +1,135 @@
+use std::fmt::{self, Display};
+
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    // one answer per input line, collapsed to a plain answer when there is a
+    // single line
+    pub fn per_line<T: Into<Answer>>(answers: Vec<T>) -> Answer {
+        let mut answers: Vec<Answer> = answers.into_iter().map(Into::into).collect();
+
+        match answers.len() {
+            1 => answers.remove(0),
+            _ => Answer::Lines(answers.iter().map(Answer::to_string).collect()),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+// "part 1: 42", with multi-line answers starting on their own line
+pub fn print_part(part: u32, answer: &Answer) {
+    match answer {
+        Answer::Lines(_) => println!("part {part}:\n{answer}"),
+        _ => println!("part {part}: {answer}"),
+    }
+}
+
+// Numbers beyond 64 bits are written as strings, which formats like JSON
+// cannot hold as numbers.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => match (i64::try_from(*n), u64::try_from(*n)) {
+                (Ok(n), _) => serializer.serialize_i64(n),
+                (_, Ok(n)) => serializer.serialize_u64(n),
+                _ => serializer.serialize_str(&n.to_string()),
+            },
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Lines(lines) => lines.serialize(serializer),
+        }
+    }
+}
+
+macro_rules! number_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+number_answer!(u32, u64, usize, i32, i64, isize);
+
+// the one integer type that does not always fit in an i128
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Answer {
+        Answer::Lines(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn answers_display_as_plain_text() {
+        assert_eq!(Answer::from(-42i32).to_string(), "-42");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::per_line(vec![1u32, 2]).to_string(), "1\n2");
+        assert_eq!(Answer::per_line(vec![7u32]), Answer::from(7u32));
+        assert_eq!(
+            Answer::from(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    fn numbers_serialise_as_numbers_while_they_fit() {
+        let value = |answer: Answer| serde_json::to_value(answer).unwrap();
+
+        assert_eq!(value(Answer::from(i64::MIN)), json!(i64::MIN));
+        assert_eq!(value(Answer::from(u64::MAX)), json!(u64::MAX));
+        assert_eq!(
+            value(Answer::from(u64::MAX as u128 + 1)),
+            json!("18446744073709551616")
+        );
+        assert_eq!(
+            value(Answer::Number(i128::MIN)),
+            json!(i128::MIN.to_string())
+        );
+        assert_eq!(value(Answer::from("CMZ")), json!("CMZ"));
+        assert_eq!(value(Answer::Lines(vec!["#.".into()])), json!(["#."]));
+    }
+}
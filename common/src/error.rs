@@ -0,0 +1,49 @@
+use std::{error::Error, fmt};
+
+// Line numbers are 1-based and refer to the whole input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    // `index` is the 0-based position of the line in the input
+    pub fn at(index: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: Some(index + 1),
+            message: message.into(),
+        }
+    }
+
+    // for errors raised while parsing a slice starting `lines` into the input
+    pub fn offset(self, lines: usize) -> ParseError {
+        ParseError {
+            line: self.line.map(|line| line + lines),
+            ..self
+        }
+    }
+
+    pub fn exit(self) -> ! {
+        eprintln!("{self}");
+        std::process::exit(1);
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,9 @@
+use std::io::stdin;
+
+pub fn read_lines() -> Vec<String> {
+    stdin().lines().map_while(Result::ok).collect()
+}
+
+pub fn split_lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
@@ -0,0 +1,7 @@
+mod answer;
+mod error;
+mod input;
+
+pub use answer::{print_part, Answer};
+pub use error::ParseError;
+pub use input::{read_lines, split_lines};
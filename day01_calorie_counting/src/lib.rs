@@ -0,0 +1,188 @@
+pub mod plan;
+pub mod report;
+
+use common::{Answer, ParseError};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Elf {
+    // position of the elf's block in the input, from 0
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    // parse makes sure this does not overflow
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+}
+
+// How blank lines that do not close a block of items are read: repeated ones
+// between blocks and any before the first or after the last block.
+#[derive(Debug, Default, Copy, Clone, PartialEq, clap::ValueEnum)]
+pub enum Separators {
+    // they are skipped
+    #[default]
+    Collapse,
+    // each one adds an elf carrying nothing
+    Keep,
+    // they are an error
+    Reject,
+}
+
+pub fn totals(elves: &[Elf]) -> impl Iterator<Item = u64> + '_ {
+    elves.iter().map(Elf::total)
+}
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let elves = parse(lines)?;
+
+    Ok(answers(&elves))
+}
+
+pub fn answers(elves: &[Elf]) -> (Answer, Answer) {
+    let max = total(&top_n(totals(elves), 1));
+    let top3 = total(&top_n(totals(elves), 3));
+
+    (max.into(), top3.into())
+}
+
+// The n largest totals, largest first. Only n totals are kept at a time, and
+// there are fewer than n results when there are fewer than n elves.
+pub fn top_n(calories: impl IntoIterator<Item = u64>, n: usize) -> Vec<u64> {
+    // `n` can be far more than the number of totals, so it is no capacity hint
+    let mut heap = BinaryHeap::new();
+
+    for total in calories {
+        heap.push(Reverse(total));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(total)| total)
+        .collect()
+}
+
+pub fn total(calories: &[u64]) -> u128 {
+    calories.iter().map(|&c| c as u128).sum()
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Elf>, ParseError> {
+    parse_with(lines, Separators::default())
+}
+
+pub fn parse_with(lines: &[String], separators: Separators) -> Result<Vec<Elf>, ParseError> {
+    let mut elves: Vec<Elf> = Vec::new();
+    let mut items: Vec<u64> = Vec::new();
+    let mut item_calories = 0u64;
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            // a blank line with no items since the last separator
+            match (items.is_empty(), separators) {
+                (true, Separators::Collapse) => continue,
+                (true, Separators::Reject) => {
+                    return Err(ParseError::at(i, "repeated blank line"));
+                }
+                _ => {}
+            }
+
+            let index = elves.len();
+            elves.push(Elf { index, items });
+            items = Vec::new();
+            item_calories = 0;
+        } else {
+            let item = line
+                .parse::<u64>()
+                .map_err(|_| ParseError::at(i, format!("invalid calories: {line:?}")))?;
+            item_calories = item_calories
+                .checked_add(item)
+                .ok_or_else(|| ParseError::at(i, "calorie total overflows"))?;
+            items.push(item);
+        }
+    }
+
+    // push the last elf's items
+    if !items.is_empty() || separators == Separators::Keep {
+        let index = elves.len();
+        elves.push(Elf { index, items });
+    }
+
+    Ok(elves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    fn items(elves: &[Elf]) -> Vec<Vec<u64>> {
+        elves.iter().map(|elf| elf.items.clone()).collect()
+    }
+
+    #[test]
+    fn repeated_separators() {
+        let lines = split_lines("\n1\n\n\n2\n");
+
+        let collapsed = parse_with(&lines, Separators::Collapse).unwrap();
+        assert_eq!(items(&collapsed), vec![vec![1], vec![2]]);
+
+        let kept = parse_with(&lines, Separators::Keep).unwrap();
+        assert_eq!(items(&kept), vec![vec![], vec![1], vec![], vec![2]]);
+
+        let rejected = parse_with(&lines, Separators::Reject);
+        assert_eq!(rejected, Err(ParseError::at(0, "repeated blank line")));
+    }
+
+    #[test]
+    fn overflow_cites_line() {
+        let lines = split_lines(&format!("1\n\n{}\n1", u64::MAX));
+
+        assert_eq!(
+            parse(&lines),
+            Err(ParseError::at(3, "calorie total overflows"))
+        );
+    }
+
+    #[test]
+    fn top_n_past_the_number_of_elves() {
+        assert_eq!(top_n([3, 1, 2], usize::MAX), [3, 2, 1]);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(elves in prop::collection::vec(prop::collection::vec(0..1u64 << 60, 1..10), 1..20)) {
+            let text = elves
+                .iter()
+                .map(|items| items.iter().map(u64::to_string).collect::<Vec<_>>().join("\n"))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+
+            let expected: Vec<Elf> = elves
+                .into_iter()
+                .enumerate()
+                .map(|(index, items)| Elf { index, items })
+                .collect();
+            prop_assert_eq!(parse(&split_lines(&text)), Ok(expected));
+        }
+
+        #[test]
+        fn top_n_matches_sorting(calories in prop::collection::vec(any::<u64>(), 0..50), n in 0..60usize) {
+            let mut expected = calories.clone();
+            expected.sort_by(|a, b| b.cmp(a));
+            expected.truncate(n);
+
+            prop_assert_eq!(top_n(calories, n), expected);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9 \n-]*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+    }
+}
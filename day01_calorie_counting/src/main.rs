@@ -1,36 +1,108 @@
-use std::io::{stdin, BufRead, BufReader};
+use clap::Parser;
+use common::{print_part, read_lines};
+use day01_calorie_counting::{
+    answers, parse_with,
+    plan::{self, Goal},
+    report, top_n, total, totals, Separators,
+};
+use std::process;
+
+#[derive(Parser)]
+struct Args {
+    /// Print the N largest calorie totals and their sum instead of solving
+    #[arg(long, value_name = "N")]
+    top: Option<usize>,
+
+    /// Print every elf's rank, total and item count with summary statistics
+    #[arg(long)]
+    report: bool,
+
+    /// Print the report as CSV
+    #[arg(long, requires = "report")]
+    csv: bool,
+
+    /// Plan item transfers so that no elf carries more than X calories
+    #[arg(long, value_name = "X", conflicts_with = "top_gap")]
+    cap: Option<u64>,
+
+    /// Plan item transfers that narrow the gap between the N largest totals
+    #[arg(long, value_name = "N")]
+    top_gap: Option<usize>,
+
+    /// Search every possible plan instead of planning greedily (small inputs only)
+    #[arg(long)]
+    exact: bool,
+
+    /// How to read blank lines that do not separate two elves
+    #[arg(long, value_enum, default_value_t)]
+    separators: Separators,
+}
 
 fn main() {
-    let stdin = stdin();
-    let reader = BufReader::new(stdin.lock());
-
-    let mut calories: Vec<u32> = Vec::new();
-    let mut item_calories = 0u32;
-
-    reader
-        .lines()
-        .filter_map(|line| match line {
-            Ok(l) => Some(l),
-            Err(_) => None,
-        })
-        .for_each(|line| {
-            if line.trim().len() == 0 {
-                calories.push(item_calories);
-                item_calories = 0;
-            } else {
-                item_calories += line.parse::<u32>().expect("invalid line");
+    let args = Args::parse();
+    let lines = read_lines();
+    let elves = parse_with(&lines, args.separators).unwrap_or_else(|err| err.exit());
+
+    if let Some(n) = args.top {
+        let top = top_n(totals(&elves), n);
+
+        for (rank, calories) in top.iter().enumerate() {
+            println!("{}: {calories}", rank + 1);
+        }
+        println!("sum: {}", total(&top));
+        return;
+    }
+
+    let goal = match (args.cap, args.top_gap) {
+        (Some(cap), _) => Some(Goal::Cap(cap)),
+        (_, Some(n)) => Some(Goal::TopGap(n)),
+        _ => None,
+    };
+    if let Some(goal) = goal {
+        let plan = match args.exact {
+            true if !plan::small_enough_for_exact(&elves) => {
+                eprintln!("too many elves and items for an exact plan");
+                process::exit(1);
             }
-        });
+            true => plan::exact(&elves, goal),
+            false => plan::greedy(&elves, goal),
+        };
+        let Some(plan) = plan else {
+            eprintln!("no plan meets the goal");
+            process::exit(1);
+        };
 
-    // push the last elf's total computed calories
-    calories.push(item_calories);
+        print_plan(&plan);
+        return;
+    }
 
-    calories.sort();
-    calories.reverse();
+    if args.report {
+        match args.csv {
+            true => report::print_csv(&elves),
+            false => report::print(&elves),
+        }
+        return;
+    }
+
+    let (part_1, part_2) = answers(&elves);
+
+    print_part(1, &part_1);
+    print_part(2, &part_2);
+}
 
-    let max = calories[0];
-    println!("part 1 result: {max}");
+fn print_plan(plan: &plan::Plan) {
+    for transfer in &plan.transfers {
+        println!(
+            "move {} from elf {} to elf {}",
+            transfer.calories,
+            transfer.from + 1,
+            transfer.to + 1
+        );
+    }
+    println!("{} transfers", plan.transfers.len());
+    println!();
 
-    let top3 = calories[..3].iter().sum::<u32>();
-    println!("part 2 result: {top3}");
+    for elf in &plan.elves {
+        println!("elf {}: {}", elf.index + 1, elf.total());
+    }
 }
@@ -0,0 +1,309 @@
+use crate::{top_n, Elf};
+
+// exact plans try every way of handing out the items, so they are limited to
+// inputs with at most this many assignments
+const EXACT_LIMIT: u64 = 1 << 20;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Goal {
+    // no elf carries more than this many calories
+    Cap(u64),
+    // the difference between the largest and the n-th largest total is as
+    // small as it can be made
+    TopGap(usize),
+}
+
+// one item handed from an elf to another, elves named by their index
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transfer {
+    pub calories: u64,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plan {
+    pub transfers: Vec<Transfer>,
+    // the inventories once every transfer is made
+    pub elves: Vec<Elf>,
+}
+
+impl Plan {
+    fn new(elves: &[Elf]) -> Self {
+        Plan {
+            transfers: Vec::new(),
+            elves: elves.to_vec(),
+        }
+    }
+
+    fn totals(&self) -> Vec<u64> {
+        self.elves.iter().map(Elf::total).collect()
+    }
+
+    // moves the item at `item` in elf `from`'s list to the end of elf `to`'s
+    fn transfer(&mut self, from: usize, item: usize, to: usize) {
+        let calories = self.elves[from].items.remove(item);
+        self.elves[to].items.push(calories);
+        self.transfers.push(Transfer {
+            calories,
+            from: self.elves[from].index,
+            to: self.elves[to].index,
+        });
+    }
+}
+
+pub fn top_gap(totals: &[u64], n: usize) -> u64 {
+    let top = top_n(totals.iter().copied(), n.max(1));
+
+    match (top.first(), top.last()) {
+        (Some(first), Some(last)) => first - last,
+        _ => 0,
+    }
+}
+
+// Moves as few items as a greedy pass can find. Returns None when a cap
+// cannot be met, either because it cannot be met at all or because the pass
+// got stuck.
+pub fn greedy(elves: &[Elf], goal: Goal) -> Option<Plan> {
+    match goal {
+        Goal::Cap(cap) => greedy_cap(elves, cap),
+        Goal::TopGap(n) => Some(greedy_top_gap(elves, n)),
+    }
+}
+
+// The most loaded elf over the cap hands its largest item that fits somewhere
+// to the elf with the most room left, until every elf is under the cap.
+fn greedy_cap(elves: &[Elf], cap: u64) -> Option<Plan> {
+    let mut plan = Plan::new(elves);
+
+    loop {
+        let totals = plan.totals();
+        let (from, &most) = totals.iter().enumerate().max_by_key(|(_, &t)| t)?;
+        if most <= cap {
+            return Some(plan);
+        }
+
+        let (to, &least) = totals.iter().enumerate().min_by_key(|(_, &t)| t)?;
+        let room = cap.saturating_sub(least);
+
+        let item = plan.elves[from]
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c > 0 && c <= room)
+            .max_by_key(|(_, &c)| c)
+            .map(|(i, _)| i)?;
+
+        plan.transfer(from, item, to);
+    }
+}
+
+// The richest elf hands over whichever item narrows the gap the most, to one
+// of the elves just below it, for as long as that narrows the gap.
+fn greedy_top_gap(elves: &[Elf], n: usize) -> Plan {
+    let mut plan = Plan::new(elves);
+
+    loop {
+        let totals = plan.totals();
+        let gap = top_gap(&totals, n);
+
+        let mut order: Vec<usize> = (0..totals.len()).collect();
+        order.sort_by_key(|&e| std::cmp::Reverse(totals[e]));
+        let Some((&from, below)) = order.split_first() else {
+            return plan;
+        };
+
+        let mut best: Option<(u64, usize, usize)> = None;
+        for &to in below.iter().take(n.max(1)) {
+            for (item, &calories) in plan.elves[from].items.iter().enumerate() {
+                // a move the receiving elf's total cannot hold is no move
+                let Some(received) = totals[to].checked_add(calories) else {
+                    continue;
+                };
+                let mut moved = totals.clone();
+                moved[from] -= calories;
+                moved[to] = received;
+
+                let new_gap = top_gap(&moved, n);
+                if new_gap < best.map_or(gap, |(g, _, _)| g) {
+                    best = Some((new_gap, item, to));
+                }
+            }
+        }
+
+        match best {
+            Some((_, item, to)) => plan.transfer(from, item, to),
+            None => return plan,
+        }
+    }
+}
+
+pub fn small_enough_for_exact(elves: &[Elf]) -> bool {
+    let items: usize = elves.iter().map(|elf| elf.items.len()).sum();
+
+    (elves.len() as u64)
+        .checked_pow(items as u32)
+        .is_some_and(|assignments| assignments <= EXACT_LIMIT)
+}
+
+// The plan with the fewest transfers among the ones that best meet the goal,
+// found by trying every assignment of items to elves. Only meant for inputs
+// that are `small_enough_for_exact`.
+pub fn exact(elves: &[Elf], goal: Goal) -> Option<Plan> {
+    let items: Vec<(usize, u64)> = elves
+        .iter()
+        .enumerate()
+        .flat_map(|(e, elf)| elf.items.iter().map(move |&c| (e, c)))
+        .collect();
+
+    let mut search = Search {
+        items: &items,
+        goal,
+        owners: Vec::with_capacity(items.len()),
+        totals: vec![0; elves.len()],
+        best: None,
+    };
+    search.run(0);
+
+    let (_, _, owners) = search.best?;
+
+    let mut plan = Plan::new(elves);
+    for elf in plan.elves.iter_mut() {
+        elf.items.clear();
+    }
+    for (&(from, calories), &to) in items.iter().zip(&owners) {
+        plan.elves[to].items.push(calories);
+        if from != to {
+            plan.transfers.push(Transfer {
+                calories,
+                from: elves[from].index,
+                to: elves[to].index,
+            });
+        }
+    }
+
+    Some(plan)
+}
+
+struct Search<'a> {
+    items: &'a [(usize, u64)],
+    goal: Goal,
+    owners: Vec<usize>,
+    totals: Vec<u64>,
+    // (gap, transfers, owners) of the best assignment so far
+    best: Option<(u64, usize, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    fn run(&mut self, moved: usize) {
+        if self
+            .best
+            .as_ref()
+            .is_some_and(|&(gap, transfers, _)| gap == 0 && moved >= transfers)
+        {
+            return;
+        }
+
+        let Some(&(owner, calories)) = self.items.get(self.owners.len()) else {
+            let gap = match self.goal {
+                Goal::Cap(_) => 0,
+                Goal::TopGap(n) => top_gap(&self.totals, n),
+            };
+            if self
+                .best
+                .as_ref()
+                .is_none_or(|&(g, t, _)| (gap, moved) < (g, t))
+            {
+                self.best = Some((gap, moved, self.owners.clone()));
+            }
+            return;
+        };
+
+        for elf in 0..self.totals.len() {
+            let Some(total) = self.totals[elf].checked_add(calories) else {
+                continue;
+            };
+            if matches!(self.goal, Goal::Cap(cap) if total > cap) {
+                continue;
+            }
+
+            self.totals[elf] = total;
+            self.owners.push(elf);
+            self.run(moved + (elf != owner) as usize);
+            self.owners.pop();
+            self.totals[elf] -= calories;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn elves(items: Vec<Vec<u64>>) -> Vec<Elf> {
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(index, items)| Elf { index, items })
+            .collect()
+    }
+
+    #[test]
+    fn cap_that_cannot_be_met() {
+        let elves = elves(vec![vec![10, 1], vec![2]]);
+
+        assert_eq!(greedy(&elves, Goal::Cap(9)), None);
+        assert_eq!(exact(&elves, Goal::Cap(9)), None);
+    }
+
+    #[test]
+    fn top_gap_moves_one_item() {
+        let elves = elves(vec![vec![6, 4], vec![5], vec![1]]);
+        let plan = greedy(&elves, Goal::TopGap(2)).unwrap();
+
+        assert_eq!(
+            plan.transfers,
+            vec![Transfer {
+                calories: 4,
+                from: 0,
+                to: 2
+            }]
+        );
+        assert_eq!(top_gap(&plan.totals(), 2), 1);
+    }
+
+    #[test]
+    fn totals_past_u64_are_never_planned() {
+        let half = 1 << 63;
+        let elves = elves(vec![vec![half, 1], vec![half]]);
+
+        for plan in [
+            greedy(&elves, Goal::TopGap(2)).unwrap(),
+            exact(&elves, Goal::TopGap(2)).unwrap(),
+        ] {
+            assert_eq!(plan.totals(), [half + 1, half]);
+        }
+        assert_eq!(exact(&elves, Goal::Cap(u64::MAX)).unwrap().transfers, []);
+    }
+
+    proptest! {
+        #[test]
+        fn exact_never_needs_more_transfers(
+            items in prop::collection::vec(prop::collection::vec(1..20u64, 0..3), 1..5),
+            cap in 10..40u64,
+        ) {
+            let elves = elves(items);
+            prop_assume!(small_enough_for_exact(&elves));
+
+            let best = exact(&elves, Goal::Cap(cap));
+            if let Some(plan) = greedy(&elves, Goal::Cap(cap)) {
+                prop_assert!(plan.totals().iter().all(|&t| t <= cap));
+
+                let best = best.unwrap();
+                prop_assert!(best.totals().iter().all(|&t| t <= cap));
+                prop_assert!(best.transfers.len() <= plan.transfers.len());
+            }
+        }
+    }
+}
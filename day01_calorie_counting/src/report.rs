@@ -0,0 +1,177 @@
+use crate::Elf;
+
+const PERCENTILES: [u32; 5] = [10, 25, 50, 75, 90];
+const HISTOGRAM_BINS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    // (percentile, total) pairs, nearest-rank
+    pub percentiles: Vec<(u32, u64)>,
+}
+
+// elves from the largest total down, ties kept in input order, with their rank
+pub fn ranking(elves: &[Elf]) -> Vec<(usize, &Elf)> {
+    let mut ranked: Vec<&Elf> = elves.iter().collect();
+    ranked.sort_by_key(|elf| std::cmp::Reverse(elf.total()));
+
+    ranked
+        .into_iter()
+        .enumerate()
+        .map(|(i, elf)| (i + 1, elf))
+        .collect()
+}
+
+pub fn stats(elves: &[Elf]) -> Option<Stats> {
+    let mut totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+    totals.sort();
+
+    let n = totals.len();
+    if n == 0 {
+        return None;
+    }
+
+    let mean = totals.iter().map(|&t| t as f64).sum::<f64>() / n as f64;
+    let median = match n % 2 {
+        1 => totals[n / 2] as f64,
+        _ => (totals[n / 2 - 1] as f64 + totals[n / 2] as f64) / 2.0,
+    };
+    let percentiles = PERCENTILES
+        .iter()
+        .map(|&p| {
+            let rank = (p as usize * n).div_ceil(100).max(1);
+            (p, totals[rank - 1])
+        })
+        .collect();
+
+    Some(Stats {
+        elves: n,
+        mean,
+        median,
+        percentiles,
+    })
+}
+
+// `bins` equal-width buckets between the smallest and largest total, each
+// drawn as a bar scaled to `width` characters
+pub fn histogram(totals: &[u64], bins: usize, width: usize) -> Vec<String> {
+    let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+        return Vec::new();
+    };
+
+    let bins = bins.min(((max - min) as usize).saturating_add(1));
+    let bin_size = (max - min) / bins as u64 + 1;
+    let mut counts = vec![0usize; bins];
+    for &total in totals {
+        counts[((total - min) / bin_size) as usize] += 1;
+    }
+    let most = *counts.iter().max().unwrap();
+
+    counts
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            let low = min + i as u64 * bin_size;
+            let high = low.saturating_add(bin_size - 1);
+            let bar = "#".repeat((count * width).div_ceil(most));
+            format!("{low:>10} - {high:>10} | {bar} {count}")
+        })
+        .collect()
+}
+
+pub fn print(elves: &[Elf]) {
+    println!("rank  elf  total  items");
+    for (rank, elf) in ranking(elves) {
+        println!(
+            "{rank:>4} {:>4} {:>6} {:>6}",
+            elf.index + 1,
+            elf.total(),
+            elf.items.len()
+        );
+    }
+
+    let Some(stats) = stats(elves) else {
+        return;
+    };
+
+    println!();
+    println!("elves: {}", stats.elves);
+    println!("mean: {:.1}", stats.mean);
+    println!("median: {:.1}", stats.median);
+    for (p, total) in &stats.percentiles {
+        println!("p{p}: {total}");
+    }
+
+    println!();
+    let totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+    for line in histogram(&totals, HISTOGRAM_BINS, HISTOGRAM_WIDTH) {
+        println!("{line}");
+    }
+}
+
+pub fn print_csv(elves: &[Elf]) {
+    println!("rank,elf,total,items");
+    for (rank, elf) in ranking(elves) {
+        println!(
+            "{rank},{},{},{}",
+            elf.index + 1,
+            elf.total(),
+            elf.items.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elves(totals: &[u64]) -> Vec<Elf> {
+        totals
+            .iter()
+            .enumerate()
+            .map(|(index, &total)| Elf {
+                index,
+                items: vec![total],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn stats_of_even_count() {
+        let stats = stats(&elves(&[4, 1, 3, 2])).unwrap();
+
+        assert_eq!(stats.mean, 2.5);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(
+            stats.percentiles,
+            vec![(10, 1), (25, 1), (50, 2), (75, 3), (90, 4)]
+        );
+    }
+
+    #[test]
+    fn ranking_keeps_ties_in_input_order() {
+        let elves = elves(&[5, 9, 5]);
+        let ranked: Vec<(usize, usize)> = ranking(&elves)
+            .into_iter()
+            .map(|(rank, elf)| (rank, elf.index))
+            .collect();
+
+        assert_eq!(ranked, vec![(1, 1), (2, 0), (3, 2)]);
+    }
+
+    #[test]
+    fn histogram_counts_every_total() {
+        let lines = histogram(&[0, 1, 10], 2, 4);
+
+        assert_eq!(
+            lines,
+            vec![
+                "         0 -          5 | #### 2",
+                "         6 -         11 | ## 1",
+            ]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use crate::game::{Game, Move};
+use common::ParseError;
+
+// What the opponent plays next given the move they played before, `None`
+// standing for any move. Probabilities are indexed like `Game::moves`, and
+// `weight` is how often the context comes up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Context<'g> {
+    pub previous: Option<Move<'g>>,
+    pub weight: f64,
+    pub next: Vec<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Model<'g>(pub Vec<Context<'g>>);
+
+impl<'g> Model<'g> {
+    // the same distribution every round
+    pub fn global(next: Vec<f64>) -> Self {
+        Model(vec![Context {
+            previous: None,
+            weight: 1.0,
+            next,
+        }])
+    }
+
+    // how often each move comes up in the opponent's moves
+    pub fn learn_global(game: &Game, opponent: &[Move]) -> Option<Self> {
+        Some(Model::global(frequencies(game, opponent.iter())?))
+    }
+
+    // how often each move follows each other one in the opponent's moves
+    pub fn learn_markov(game: &'g Game, opponent: &[Move<'g>]) -> Option<Self> {
+        let contexts: Vec<Context> = game
+            .moves()
+            .filter_map(|previous| {
+                let following = opponent
+                    .windows(2)
+                    .filter(|pair| pair[0] == previous)
+                    .map(|pair| &pair[1]);
+                let next = frequencies(game, following.clone())?;
+                Some(Context {
+                    previous: Some(previous),
+                    weight: following.count() as f64 / (opponent.len() - 1) as f64,
+                    next,
+                })
+            })
+            .collect();
+
+        match contexts.is_empty() {
+            true => None,
+            false => Some(Model(contexts)),
+        }
+    }
+
+    // Expected score of a round when `policy` picks the response from the
+    // opponent's previous move.
+    pub fn expected(&self, policy: impl Fn(Option<Move<'g>>) -> Move<'g>) -> f64 {
+        self.0
+            .iter()
+            .map(|context| {
+                context.weight * expected_against(&context.next, policy(context.previous))
+            })
+            .sum()
+    }
+}
+
+fn frequencies<'a, 'g: 'a>(
+    game: &Game,
+    moves: impl Iterator<Item = &'a Move<'g>>,
+) -> Option<Vec<f64>> {
+    let mut counts = vec![0.0; game.moves().count()];
+    for m in moves {
+        counts[m.index()] += 1.0;
+    }
+
+    let total: f64 = counts.iter().sum();
+    match total > 0.0 {
+        true => Some(counts.into_iter().map(|c| c / total).collect()),
+        false => None,
+    }
+}
+
+// the expected score of playing `response` against a move drawn from `next`
+fn expected_against(next: &[f64], response: Move) -> f64 {
+    response
+        .game()
+        .moves()
+        .map(|op| next[op.index()] * response.play(&op) as f64)
+        .sum()
+}
+
+// Moves by name with their weights, like `rock=2,paper=1`, normalised to
+// probabilities. Moves left out never come up.
+pub fn parse_distribution(game: &Game, s: &str) -> Result<Vec<f64>, ParseError> {
+    let mut weights = vec![0.0; game.moves().count()];
+
+    for entry in s.split(',') {
+        let (name, weight) = entry
+            .split_once('=')
+            .ok_or_else(|| ParseError::new(format!("expected move=weight: {entry:?}")))?;
+        let m = game
+            .moves()
+            .find(|m| m.name() == name.trim())
+            .ok_or_else(|| ParseError::new(format!("unknown move: {name:?}")))?;
+        weights[m.index()] = weight
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|w| w.is_finite() && *w >= 0.0)
+            .ok_or_else(|| ParseError::new(format!("invalid weight: {weight:?}")))?;
+    }
+
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return Err(ParseError::new("the weights add up to nothing"));
+    }
+
+    Ok(weights.into_iter().map(|w| w / total).collect())
+}
+
+// The score is linear in the probabilities of a mixed strategy, so the best
+// one in each context spreads over the responses that are best on their own.
+pub fn best_mixed<'g>(game: &'g Game, next: &[f64]) -> Vec<(Move<'g>, f64)> {
+    let scores: Vec<(Move, f64)> = game
+        .moves()
+        .map(|m| (m, expected_against(next, m)))
+        .collect();
+    let best = scores.iter().map(|&(_, s)| s).fold(f64::MIN, f64::max);
+
+    let tied: Vec<Move> = scores
+        .into_iter()
+        .filter(|&(_, s)| best - s < 1e-9)
+        .map(|(m, _)| m)
+        .collect();
+    let share = 1.0 / tied.len() as f64;
+
+    tied.into_iter().map(|m| (m, share)).collect()
+}
+
+pub fn print(game: &Game, model: &Model) {
+    println!("{:<20}  expected score", "policy");
+    for m in game.moves() {
+        let name = format!("always {m}");
+        println!("{name:<20}  {:>14.3}", model.expected(|_| m));
+    }
+
+    let conditional = |previous: Option<Move>| {
+        let context = model.0.iter().find(|c| c.previous == previous).unwrap();
+        best_mixed(game, &context.next)[0].0
+    };
+    println!(
+        "{:<20}  {:>14.3}",
+        "best response",
+        model.expected(conditional)
+    );
+
+    println!();
+    println!("best mixed strategy");
+    for context in &model.0 {
+        let mix: Vec<String> = best_mixed(game, &context.next)
+            .iter()
+            .map(|(m, p)| format!("{m} {p:.3}"))
+            .collect();
+        match context.previous {
+            Some(previous) => println!("  after {previous}: {}", mix.join(", ")),
+            None => println!("  every round: {}", mix.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_score_of_a_uniform_opponent() {
+        let game = Game::classic();
+        let model = Model::global(parse_distribution(&game, "rock=1,paper=1,scissors=1").unwrap());
+
+        // every shape draws, wins and loses once: (shape * 3 + 9) / 3
+        for (m, score) in game.moves().zip([4.0, 5.0, 6.0]) {
+            assert!((model.expected(|_| m) - score).abs() < 1e-9);
+        }
+        assert_eq!(best_mixed(&game, &model.0[0].next).len(), 1);
+    }
+
+    #[test]
+    fn markov_chain_predicts_a_cycle() {
+        let game = Game::classic();
+        let moves: Vec<Move> = game.moves().collect();
+        let cycle: Vec<Move> = moves.iter().copied().cycle().take(31).collect();
+        let model = Model::learn_markov(&game, &cycle).unwrap();
+
+        let beat_next = |previous: Option<Move>| {
+            let context = model.0.iter().find(|c| c.previous == previous).unwrap();
+            best_mixed(&game, &context.next)[0].0
+        };
+        // scissors after rock, rock after paper, paper after scissors
+        assert!((model.expected(beat_next) - (9.0 + 7.0 + 8.0) / 3.0).abs() < 1e-9);
+        assert!(parse_distribution(&game, "lizard=1").is_err());
+    }
+}
@@ -0,0 +1,303 @@
+use common::{split_lines, ParseError};
+use std::{fmt, ptr};
+
+const CLASSIC: &str = include_str!("../games/classic.txt");
+
+pub const LOSS_SCORE: u32 = 0;
+pub const DRAW_SCORE: u32 = 3;
+pub const WIN_SCORE: u32 = 6;
+
+// One line per move: its name, shape score, the letters standing for it in the
+// opponent's and in the response column of the guide, then the moves it beats.
+//
+//     rock 1 A X scissors
+//
+// Every two different moves must be decided one way, and every move must beat
+// as many moves as it loses to.
+#[derive(Debug)]
+pub struct Game {
+    moves: Vec<MoveDef>,
+    // beats[a][b] when move a beats move b
+    beats: Vec<Vec<bool>>,
+}
+
+#[derive(Debug)]
+struct MoveDef {
+    name: String,
+    score: u32,
+    opponent: String,
+    response: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    pub fn score(&self) -> u32 {
+        match self {
+            Outcome::Loss => LOSS_SCORE,
+            Outcome::Draw => DRAW_SCORE,
+            Outcome::Win => WIN_SCORE,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Outcome::Loss => "loss",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Move<'g> {
+    game: &'g Game,
+    index: usize,
+}
+
+impl Game {
+    pub fn classic() -> Game {
+        Game::parse(&split_lines(CLASSIC)).expect("the classic game is valid")
+    }
+
+    pub fn parse(lines: &[String]) -> Result<Game, ParseError> {
+        let mut moves = Vec::new();
+        let mut beaten: Vec<(usize, Vec<String>)> = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let [name, score, opponent, response, beats @ ..] = tokens.as_slice() else {
+                return Err(ParseError::at(i, "expected a name, score and two letters"));
+            };
+            let score = score
+                .parse()
+                .map_err(|_| ParseError::at(i, format!("invalid score: {score:?}")))?;
+
+            for (taken, what) in [
+                (moves.iter().any(|m: &MoveDef| m.name == *name), "move"),
+                (
+                    moves.iter().any(|m| m.opponent == *opponent),
+                    "opponent letter",
+                ),
+                (
+                    moves.iter().any(|m| m.response == *response),
+                    "response letter",
+                ),
+            ] {
+                if taken {
+                    return Err(ParseError::at(i, format!("duplicate {what}")));
+                }
+            }
+
+            moves.push(MoveDef {
+                name: name.to_string(),
+                score,
+                opponent: opponent.to_string(),
+                response: response.to_string(),
+            });
+            beaten.push((i, beats.iter().map(|b| b.to_string()).collect()));
+        }
+
+        if moves.len() < 3 {
+            return Err(ParseError::new("a game needs at least three moves"));
+        }
+
+        let n = moves.len();
+        let mut beats = vec![vec![false; n]; n];
+        for (a, (i, names)) in beaten.iter().enumerate() {
+            for name in names {
+                let b = moves
+                    .iter()
+                    .position(|m| m.name == *name)
+                    .ok_or_else(|| ParseError::at(*i, format!("unknown move: {name:?}")))?;
+                if a == b {
+                    return Err(ParseError::at(*i, "a move cannot beat itself"));
+                }
+                beats[a][b] = true;
+            }
+        }
+
+        for (a, &(i, _)) in beaten.iter().enumerate() {
+            for b in 0..n {
+                let name = &moves[b].name;
+                if a != b && beats[a][b] == beats[b][a] {
+                    let problem = match beats[a][b] {
+                        true => "both beat each other",
+                        false => "neither beats the other",
+                    };
+                    return Err(ParseError::at(
+                        i,
+                        format!("{} and {name}: {problem}", moves[a].name),
+                    ));
+                }
+            }
+
+            let wins = beats[a].iter().filter(|&&b| b).count();
+            if 2 * wins != n - 1 {
+                return Err(ParseError::at(
+                    i,
+                    format!(
+                        "{} beats {wins} moves and loses to {}",
+                        moves[a].name,
+                        n - 1 - wins
+                    ),
+                ));
+            }
+        }
+
+        Ok(Game { moves, beats })
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move<'_>> {
+        (0..self.moves.len()).map(|index| Move { game: self, index })
+    }
+
+    pub fn opponent_move(&self, letter: &str) -> Option<Move<'_>> {
+        self.moves().find(|m| m.def().opponent == letter)
+    }
+
+    pub fn response_move(&self, letter: &str) -> Option<Move<'_>> {
+        self.moves().find(|m| m.def().response == letter)
+    }
+}
+
+impl<'g> Move<'g> {
+    fn def(&self) -> &'g MoveDef {
+        &self.game.moves[self.index]
+    }
+
+    pub fn game(&self) -> &'g Game {
+        self.game
+    }
+
+    // position among the game's moves, in the order of the config
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn name(&self) -> &'g str {
+        &self.def().name
+    }
+
+    pub fn score(&self) -> u32 {
+        self.def().score
+    }
+
+    pub fn beats(&self, other: &Move) -> bool {
+        self.game.beats[self.index][other.index]
+    }
+
+    pub fn outcome(&self, other: &Move) -> Outcome {
+        if self == other {
+            Outcome::Draw
+        } else if self.beats(other) {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    // the move to play against this one to get `outcome`, the highest scoring
+    // one when there are several
+    pub fn determine_move(&self, outcome: Outcome) -> Move<'g> {
+        self.game
+            .moves()
+            .filter(|m| m.outcome(self) == outcome)
+            .max_by_key(|m| (m.score(), std::cmp::Reverse(m.index)))
+            .expect("every game has a move for every outcome")
+    }
+
+    pub fn play(&self, other: &Move) -> u32 {
+        self.score() + self.outcome(other).score()
+    }
+}
+
+impl PartialEq for Move<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.game, other.game) && self.index == other.index
+    }
+}
+
+impl Eq for Move<'_> {}
+
+impl fmt::Debug for Move<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl fmt::Display for Move<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn game(config: &str) -> Result<Game, ParseError> {
+        Game::parse(&split_lines(config))
+    }
+
+    #[test]
+    fn bundled_games_are_valid() {
+        game(CLASSIC).unwrap();
+        game(include_str!("../games/rpsls.txt")).unwrap();
+    }
+
+    #[test]
+    fn inconsistent_games_are_rejected() {
+        let both = "a 1 A X b\nb 2 B Y a c\nc 3 C Z a";
+        assert_eq!(game(both).unwrap_err().line, Some(1));
+
+        let neither = "a 1 A X\nb 2 B Y\nc 3 C Z";
+        assert_eq!(game(neither).unwrap_err().line, Some(1));
+
+        let unbalanced = "a 1 A W b c d\nb 2 B X c\nc 3 C Y d\nd 4 D Z b";
+        assert!(game(unbalanced).is_err());
+
+        assert!(game("a 1 A X b").is_err());
+        assert!(game("a 1 A X a").is_err());
+    }
+
+    // move i beats the (n - 1) / 2 moves after it, wrapping around
+    fn cyclic(n: usize) -> String {
+        (0..n)
+            .map(|i| {
+                let beats: Vec<String> = (1..=n / 2).map(|d| format!("m{}", (i + d) % n)).collect();
+                format!("m{i} {} o{i} r{i} {}", i + 1, beats.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn cyclic_games_have_every_outcome(half in 1..6usize) {
+            let game = game(&cyclic(2 * half + 1)).unwrap();
+
+            for op in game.moves() {
+                for outcome in Outcome::ALL {
+                    prop_assert_eq!(op.determine_move(outcome).outcome(&op), outcome);
+                }
+            }
+        }
+    }
+}
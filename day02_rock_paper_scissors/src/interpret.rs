@@ -0,0 +1,136 @@
+use crate::game::{Game, Move, Outcome};
+use std::fmt;
+
+// What a letter in the guide's second column could stand for.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Meaning<'g> {
+    Move(Move<'g>),
+    Outcome(Outcome),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interpretation<'g> {
+    // each letter of the second column with its meaning, letters sorted
+    pub meanings: Vec<(String, Meaning<'g>)>,
+    pub total: u32,
+}
+
+impl fmt::Display for Meaning<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Meaning::Move(m) => write!(f, "{m}"),
+            Meaning::Outcome(outcome) => write!(f, "{outcome}"),
+        }
+    }
+}
+
+// Every way of reading the second column, either as distinct moves or as
+// distinct outcomes, scored over the whole guide, from the best total down.
+pub fn all<'g>(game: &'g Game, rounds: &[(String, String)]) -> Vec<Interpretation<'g>> {
+    let mut letters: Vec<&str> = rounds.iter().map(|(_, elf)| elf.as_str()).collect();
+    letters.sort();
+    letters.dedup();
+
+    let moves: Vec<Meaning> = game.moves().map(Meaning::Move).collect();
+    let outcomes: Vec<Meaning> = Outcome::ALL.into_iter().map(Meaning::Outcome).collect();
+
+    let mut result: Vec<Interpretation> = arrangements(&moves, letters.len())
+        .into_iter()
+        .chain(arrangements(&outcomes, letters.len()))
+        .map(|meanings| {
+            let meanings: Vec<(String, Meaning)> = letters
+                .iter()
+                .map(|l| l.to_string())
+                .zip(meanings)
+                .collect();
+            let total = score(game, rounds, &meanings);
+            Interpretation { meanings, total }
+        })
+        .collect();
+
+    result.sort_by_key(|interpretation| std::cmp::Reverse(interpretation.total));
+    result
+}
+
+fn score(game: &Game, rounds: &[(String, String)], meanings: &[(String, Meaning)]) -> u32 {
+    rounds
+        .iter()
+        .map(|(op, elf)| {
+            let op = game.opponent_move(op).unwrap();
+            let (_, meaning) = meanings.iter().find(|(letter, _)| letter == elf).unwrap();
+            match meaning {
+                Meaning::Move(m) => m.play(&op),
+                Meaning::Outcome(outcome) => op.determine_move(*outcome).play(&op),
+            }
+        })
+        .sum()
+}
+
+// every ordered choice of k distinct items
+fn arrangements<T: Copy>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+
+    let mut result = Vec::new();
+    for (i, &first) in items.iter().enumerate() {
+        let rest: Vec<T> = items
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, &item)| item)
+            .collect();
+        for mut tail in arrangements(&rest, k - 1) {
+            tail.insert(0, first);
+            result.push(tail);
+        }
+    }
+
+    result
+}
+
+pub fn print(interpretations: &[Interpretation]) {
+    println!("rank  total  meaning");
+    for (i, interpretation) in interpretations.iter().enumerate() {
+        let meanings: Vec<String> = interpretation
+            .meanings
+            .iter()
+            .map(|(letter, meaning)| format!("{letter}={meaning}"))
+            .collect();
+        println!(
+            "{:>4} {:>6}  {}",
+            i + 1,
+            interpretation.total,
+            meanings.join(" ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use common::split_lines;
+
+    #[test]
+    fn includes_both_puzzle_readings() {
+        let game = Game::classic();
+        let rounds = parse(&split_lines(include_str!("../example.txt"))).unwrap();
+        let all = all(&game, &rounds);
+
+        let reading = |all: &[Interpretation], names: [&str; 3]| {
+            all.iter()
+                .find(|i| {
+                    let found: Vec<String> =
+                        i.meanings.iter().map(|(_, m)| m.to_string()).collect();
+                    found == names
+                })
+                .map(|i| i.total)
+        };
+
+        assert_eq!(all.len(), 12);
+        assert_eq!(reading(&all, ["rock", "paper", "scissors"]), Some(15));
+        assert_eq!(reading(&all, ["loss", "draw", "win"]), Some(12));
+        assert!(all.windows(2).all(|w| w[0].total >= w[1].total));
+    }
+}
@@ -0,0 +1,139 @@
+pub mod expected;
+pub mod game;
+pub mod interpret;
+pub mod optimal;
+pub mod tournament;
+
+use common::{Answer, ParseError};
+use game::{Game, Move, Outcome};
+use std::cmp::Ordering;
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    solve_with(lines, &Game::classic())
+}
+
+pub fn solve_with(lines: &[String], game: &Game) -> Result<(Answer, Answer), ParseError> {
+    let rounds = parse_with(lines, game)?;
+
+    Ok((part_1(game, &rounds).into(), part_2(game, &rounds)?.into()))
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<(String, String)>, ParseError> {
+    parse_with(lines, &Game::classic())
+}
+
+// rounds as the opponent's letter and the letter that follows it, both known
+// to the game
+pub fn parse_with(lines: &[String], game: &Game) -> Result<Vec<(String, String)>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let moves = line.trim().split(' ').collect::<Vec<&str>>();
+            match moves.as_slice() {
+                [op, elf]
+                    if game.opponent_move(op).is_some() && game.response_move(elf).is_some() =>
+                {
+                    Ok((String::from(*op), String::from(*elf)))
+                }
+                _ => Err(ParseError::at(i, format!("invalid round: {line:?}"))),
+            }
+        })
+        .collect()
+}
+
+// The game's response letters in part 2, in the order of the config: as
+// many ask for a loss as ask for a win, with the middle one asking for a draw.
+// For the classic game that makes X, Y and Z a loss, a draw and a win.
+pub fn parse_outcome(game: &Game, s: &str) -> Option<Outcome> {
+    let index = game.response_move(s)?.index();
+    let middle = game.moves().count() / 2;
+    Some(match index.cmp(&middle) {
+        Ordering::Less => Outcome::Loss,
+        Ordering::Equal => Outcome::Draw,
+        Ordering::Greater => Outcome::Win,
+    })
+}
+
+// How the second column of the guide is read: part 1 takes it as the move to
+// play, part 2 as the outcome to aim for.
+#[derive(Debug, Default, Copy, Clone, PartialEq, clap::ValueEnum)]
+pub enum Reading {
+    Moves,
+    #[default]
+    Outcomes,
+}
+
+// the opponent's move and the move the guide prescribes, for every round
+pub fn guide_moves<'g>(
+    game: &'g Game,
+    rounds: &[(String, String)],
+    reading: Reading,
+) -> Result<Vec<(Move<'g>, Move<'g>)>, ParseError> {
+    rounds
+        .iter()
+        .enumerate()
+        .map(|(i, (op, elf))| {
+            let op = game.opponent_move(op).unwrap();
+            let elf = match reading {
+                Reading::Moves => game.response_move(elf).unwrap(),
+                Reading::Outcomes => {
+                    let outcome = parse_outcome(game, elf)
+                        .ok_or_else(|| ParseError::at(i, format!("{elf:?} is not an outcome")))?;
+                    op.determine_move(outcome)
+                }
+            };
+            Ok((op, elf))
+        })
+        .collect()
+}
+
+pub fn total(moves: &[(Move, Move)]) -> u32 {
+    moves.iter().map(|(op, elf)| elf.play(op)).sum()
+}
+
+fn part_1(game: &Game, rounds: &[(String, String)]) -> u32 {
+    total(&guide_moves(game, rounds, Reading::Moves).unwrap())
+}
+
+fn part_2(game: &Game, rounds: &[(String, String)]) -> Result<u32, ParseError> {
+    Ok(total(&guide_moves(game, rounds, Reading::Outcomes)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    #[test]
+    fn solves_games_with_their_own_letters() {
+        let game = Game::parse(&split_lines(include_str!("../games/rpsls.txt"))).unwrap();
+        let guide = split_lines("A V\nB X\nE Z");
+
+        // rock draws, scissors beats paper, spock draws; then lizard loses to
+        // rock, paper draws and lizard beats spock
+        assert_eq!(
+            solve_with(&guide, &game),
+            Ok((Answer::from(4 + 9 + 8), Answer::from(4 + 5 + 10)))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rounds in prop::collection::vec(("[ABC]", "[XYZ]"), 0..50)) {
+            let text = rounds
+                .iter()
+                .map(|(op, elf)| format!("{op} {elf}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            prop_assert_eq!(parse(&split_lines(&text)), Ok(rounds));
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[ABCXYZ \n]*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+    }
+}
@@ -1,107 +1,123 @@
-use std::io::{stdin, BufRead, BufReader};
+use clap::Parser;
+use common::{print_part, read_lines, split_lines};
+use day02_rock_paper_scissors::{
+    expected, game::Game, guide_moves, interpret, optimal, parse_with, solve_with, tournament,
+    Reading,
+};
+use std::{fs, path::PathBuf};
 
-#[derive(Copy, Clone, PartialEq, Debug)]
-enum Move {
-    Rock,
-    Paper,
-    Scissor,
+#[derive(Parser)]
+struct Args {
+    /// Game definition to play instead of rock, paper, scissors
+    #[arg(long)]
+    game: Option<PathBuf>,
+
+    /// Score the guide under every reading of its second column instead of solving
+    #[arg(long)]
+    interpretations: bool,
+
+    /// Compare every round of the guide with the best possible response instead of solving
+    #[arg(long)]
+    breakdown: bool,
+
+    /// How the guide's second column is read for the breakdown and the guide bot
+    #[arg(long, value_enum, default_value_t)]
+    reading: Reading,
+
+    /// Play a round-robin tournament between the bundled bots instead of solving
+    #[arg(long)]
+    tournament: bool,
+
+    /// Rounds in every pairing of the tournament
+    #[arg(long, default_value_t = 1000)]
+    rounds: usize,
+
+    /// Expected score of each response against a model of the opponent instead of solving
+    #[arg(long)]
+    expected: bool,
+
+    /// Opponent's moves as weights, like rock=2,paper=1, learned from the guide when missing
+    #[arg(long, conflicts_with = "markov")]
+    distribution: Option<String>,
+
+    /// Learn the opponent's next move from their previous one
+    #[arg(long)]
+    markov: bool,
+
+    /// Seed for the tournament, picked at random when missing
+    #[arg(long)]
+    seed: Option<u64>,
 }
 
-impl Move {
-    fn parse(s: &str) -> Self {
-        match s {
-            "A" | "X" => Self::Rock,
-            "B" | "Y" => Self::Paper,
-            "C" | "Z" => Self::Scissor,
-            _ => {
-                panic!("invalid move");
-            }
+fn main() {
+    let args = Args::parse();
+    let game = match args.game {
+        Some(path) => {
+            let config = fs::read_to_string(&path).unwrap_or_else(|err| {
+                eprintln!("{}: {err}", path.display());
+                std::process::exit(1);
+            });
+            Game::parse(&split_lines(&config)).unwrap_or_else(|err| err.exit())
         }
-    }
+        None => Game::classic(),
+    };
 
-    fn score(&self) -> u32 {
-        match self {
-            Move::Rock => 1,
-            Move::Paper => 2,
-            Move::Scissor => 3,
-        }
-    }
+    let lines = read_lines();
 
-    fn wins_agains(&self) -> Move {
-        match self {
-            Move::Rock => Move::Scissor,
-            Move::Paper => Move::Rock,
-            Move::Scissor => Move::Paper,
-        }
+    if args.interpretations {
+        let rounds = parse_with(&lines, &game).unwrap_or_else(|err| err.exit());
+        interpret::print(&interpret::all(&game, &rounds));
+        return;
     }
 
-    fn loses_against(&self) -> Move {
-        match self {
-            Move::Rock => Move::Paper,
-            Move::Paper => Move::Scissor,
-            Move::Scissor => Move::Rock,
-        }
+    if args.breakdown {
+        let rounds = parse_with(&lines, &game)
+            .and_then(|rounds| guide_moves(&game, &rounds, args.reading))
+            .unwrap_or_else(|err| err.exit());
+        optimal::print(&optimal::breakdown(&game, &rounds));
+        return;
     }
 
-    fn determine_move(&self, outcome: &str) -> Move {
-        match outcome {
-            "X" => self.wins_agains(),
-            "Y" => *self,
-            "Z" => self.loses_against(),
-            _ => panic!("invalid outcome"),
-        }
+    if args.expected {
+        let model = match &args.distribution {
+            Some(spec) => expected::parse_distribution(&game, spec)
+                .map(expected::Model::global)
+                .unwrap_or_else(|err| err.exit()),
+            None => {
+                let rounds = parse_with(&lines, &game).unwrap_or_else(|err| err.exit());
+                let opponent: Vec<_> = rounds
+                    .iter()
+                    .map(|(op, _)| game.opponent_move(op).unwrap())
+                    .collect();
+                let model = match args.markov {
+                    true => expected::Model::learn_markov(&game, &opponent),
+                    false => expected::Model::learn_global(&game, &opponent),
+                };
+                model.unwrap_or_else(|| {
+                    eprintln!("the guide has too few rounds to learn from");
+                    std::process::exit(1);
+                })
+            }
+        };
+        expected::print(&game, &model);
+        return;
     }
 
-    fn play(&self, other: &Move) -> u32 {
-        let score = self.score();
+    if args.tournament {
+        let guide = parse_with(&lines, &game)
+            .and_then(|rounds| guide_moves(&game, &rounds, args.reading))
+            .unwrap_or_else(|err| err.exit());
+        let guide = guide.into_iter().map(|(_, elf)| elf).collect();
 
-        if self == other {
-            score + 3
-        } else if self.wins_agains() == *other {
-            score + 6
-        } else {
-            score
-        }
+        let seed = args.seed.unwrap_or_else(rand::random);
+        let bots = tournament::bots(&game, guide);
+        tournament::print(&tournament::round_robin(&bots, args.rounds, seed));
+        eprintln!("seed {seed}");
+        return;
     }
-}
-
-fn main() {
-    let stdin = stdin();
-    let reader = BufReader::new(stdin.lock());
-
-    let rounds = reader
-        .lines()
-        .filter_map(Result::ok)
-        .map(|line| {
-            let moves = line.trim().split(' ').collect::<Vec<&str>>();
-            (String::from(moves[0]), String::from(moves[1]))
-        })
-        .collect::<Vec<(String, String)>>();
-
-    part_1(&rounds);
-    part_2(&rounds);
-}
-
-fn part_1(rounds: &Vec<(String, String)>) {
-    let score = rounds
-        .iter()
-        .map(|(m1, m2)| (Move::parse(m1), Move::parse(m2)))
-        .map(|(op, elf)| elf.play(&op))
-        .fold(0, |a, b| a + b);
 
-    println!("part 1: {score}");
-}
+    let (part_1, part_2) = solve_with(&lines, &game).unwrap_or_else(|err| err.exit());
 
-fn part_2(rounds: &Vec<(String, String)>) {
-    let score = rounds
-        .iter()
-        .map(|(mv, out)| {
-            let mv1 = Move::parse(mv);
-            let mv2 = mv1.determine_move(out);
-            (mv1, mv2)
-        })
-        .map(|(op, elf)| elf.play(&op))
-        .fold(0, |a, b| a + b);
-
-    println!("part 2: {score}");
+    print_part(1, &part_1);
+    print_part(2, &part_2);
 }
@@ -0,0 +1,99 @@
+use crate::game::{Game, Move};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Round<'g> {
+    pub opponent: Move<'g>,
+    pub guide: Move<'g>,
+    pub best: Move<'g>,
+}
+
+impl Round<'_> {
+    pub fn guide_score(&self) -> u32 {
+        self.guide.play(&self.opponent)
+    }
+
+    pub fn best_score(&self) -> u32 {
+        self.best.play(&self.opponent)
+    }
+}
+
+// the highest scoring move against `op`, the first of the game's moves when
+// several score the same
+pub fn best_response<'g>(game: &'g Game, op: &Move<'g>) -> Move<'g> {
+    game.moves()
+        .fold(None, |best: Option<Move>, m| match best {
+            Some(b) if b.play(op) >= m.play(op) => Some(b),
+            _ => Some(m),
+        })
+        .expect("every game has moves")
+}
+
+// pairs the guide's move in every round with the best move it could have been
+pub fn breakdown<'g>(game: &'g Game, guide: &[(Move<'g>, Move<'g>)]) -> Vec<Round<'g>> {
+    guide
+        .iter()
+        .map(|&(opponent, guide)| Round {
+            opponent,
+            guide,
+            best: best_response(game, &opponent),
+        })
+        .collect()
+}
+
+pub fn max_total(rounds: &[Round]) -> u32 {
+    rounds.iter().map(Round::best_score).sum()
+}
+
+// one line per round, rounds where the guide falls short marked with `*`
+pub fn print(rounds: &[Round]) {
+    println!("round  opponent  guide     shape  outcome      best      score");
+    for (i, round) in rounds.iter().enumerate() {
+        let outcome = round.guide.outcome(&round.opponent);
+        let marker = match round.guide_score() < round.best_score() {
+            true => '*',
+            false => ' ',
+        };
+        println!(
+            "{:>5}  {:<8}  {:<8}  {:>5}  {:>2} {:<8}  {:<8}  {:>2} -> {:>2} {marker}",
+            i + 1,
+            round.opponent,
+            round.guide,
+            round.guide.score(),
+            outcome.score(),
+            outcome,
+            round.best,
+            round.guide_score(),
+            round.best_score(),
+        );
+    }
+
+    let guide_total: u32 = rounds.iter().map(Round::guide_score).sum();
+    let differing = rounds
+        .iter()
+        .filter(|round| round.guide_score() < round.best_score())
+        .count();
+
+    println!();
+    println!("guide total: {guide_total}");
+    println!("maximum total: {}", max_total(rounds));
+    println!("rounds to change: {differing} of {}", rounds.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{game::Game, guide_moves, parse, Reading};
+    use common::split_lines;
+
+    #[test]
+    fn best_response_wins_with_the_best_shape() {
+        let game = Game::classic();
+        let rounds = parse(&split_lines(include_str!("../example.txt"))).unwrap();
+        let guide = guide_moves(&game, &rounds, Reading::Outcomes).unwrap();
+        let rounds = breakdown(&game, &guide);
+
+        // paper beats rock (8), scissors beats paper (9), rock beats scissors (7)
+        assert_eq!(max_total(&rounds), 24);
+        assert!(rounds.iter().all(|r| r.best_score() >= r.guide_score()));
+    }
+}
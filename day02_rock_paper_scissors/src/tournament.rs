@@ -0,0 +1,240 @@
+use crate::{
+    game::{Game, Move, Outcome},
+    optimal::best_response,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+// one round as seen by one of the players
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Turn<'g> {
+    pub own: Move<'g>,
+    pub other: Move<'g>,
+}
+
+// A bot picks its next move from the rounds played so far in the current
+// pairing, oldest first. Any randomness comes from `rng` so that tournaments
+// can be replayed from their seed.
+pub trait Strategy<'g> {
+    fn name(&self) -> String;
+    fn choose(&self, history: &[Turn<'g>], rng: &mut StdRng) -> Move<'g>;
+}
+
+// always the same move
+pub struct Fixed<'g>(pub Move<'g>);
+
+// the game's moves in order, round after round
+pub struct Cycling<'g>(pub Vec<Move<'g>>);
+
+// the best response to the opponent's most frequent move so far
+pub struct FrequencyCounter<'g>(pub &'g Game);
+
+// the opponent's previous move, a random one to open
+pub struct CopyLast<'g>(pub Vec<Move<'g>>);
+
+pub struct Random<'g>(pub Vec<Move<'g>>);
+
+// the guide's moves replayed, starting over once they run out, so there must
+// be at least one
+pub struct Guide<'g>(pub Vec<Move<'g>>);
+
+impl<'g> Strategy<'g> for Fixed<'g> {
+    fn name(&self) -> String {
+        format!("always {}", self.0)
+    }
+
+    fn choose(&self, _: &[Turn<'g>], _: &mut StdRng) -> Move<'g> {
+        self.0
+    }
+}
+
+impl<'g> Strategy<'g> for Cycling<'g> {
+    fn name(&self) -> String {
+        String::from("cycling")
+    }
+
+    fn choose(&self, history: &[Turn<'g>], _: &mut StdRng) -> Move<'g> {
+        self.0[history.len() % self.0.len()]
+    }
+}
+
+impl<'g> Strategy<'g> for FrequencyCounter<'g> {
+    fn name(&self) -> String {
+        String::from("frequency")
+    }
+
+    fn choose(&self, history: &[Turn<'g>], rng: &mut StdRng) -> Move<'g> {
+        let counts: Vec<(Move, usize)> = self
+            .0
+            .moves()
+            .map(|m| (m, history.iter().filter(|turn| turn.other == m).count()))
+            .collect();
+        let most = counts.iter().map(|&(_, count)| count).max().unwrap_or(0);
+        let likely: Vec<Move> = counts
+            .into_iter()
+            .filter(|&(_, count)| count == most)
+            .map(|(m, _)| m)
+            .collect();
+
+        let expected = likely.choose(rng).expect("every game has moves");
+        best_response(self.0, expected)
+    }
+}
+
+impl<'g> Strategy<'g> for CopyLast<'g> {
+    fn name(&self) -> String {
+        String::from("copy last")
+    }
+
+    fn choose(&self, history: &[Turn<'g>], rng: &mut StdRng) -> Move<'g> {
+        match history.last() {
+            Some(turn) => turn.other,
+            None => *self.0.choose(rng).expect("every game has moves"),
+        }
+    }
+}
+
+impl<'g> Strategy<'g> for Random<'g> {
+    fn name(&self) -> String {
+        String::from("random")
+    }
+
+    fn choose(&self, _: &[Turn<'g>], rng: &mut StdRng) -> Move<'g> {
+        *self.0.choose(rng).expect("every game has moves")
+    }
+}
+
+impl<'g> Strategy<'g> for Guide<'g> {
+    fn name(&self) -> String {
+        String::from("guide")
+    }
+
+    fn choose(&self, history: &[Turn<'g>], _: &mut StdRng) -> Move<'g> {
+        self.0[history.len() % self.0.len()]
+    }
+}
+
+// Every bundled bot for `game`, with the guide's moves replayed when there
+// are any.
+pub fn bots<'g>(game: &'g Game, guide: Vec<Move<'g>>) -> Vec<Box<dyn Strategy<'g> + 'g>> {
+    let moves: Vec<Move> = game.moves().collect();
+
+    let mut bots: Vec<Box<dyn Strategy>> = moves
+        .iter()
+        .map(|&m| Box::new(Fixed(m)) as Box<dyn Strategy>)
+        .collect();
+    bots.push(Box::new(Cycling(moves.clone())));
+    bots.push(Box::new(FrequencyCounter(game)));
+    bots.push(Box::new(CopyLast(moves.clone())));
+    bots.push(Box::new(Random(moves)));
+    if !guide.is_empty() {
+        bots.push(Box::new(Guide(guide)));
+    }
+
+    bots
+}
+
+// the results of one pairing, wins, draws and losses counted for `first`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pairing {
+    pub first: String,
+    pub second: String,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub first_score: u32,
+    pub second_score: u32,
+}
+
+pub fn play<'g>(
+    first: &dyn Strategy<'g>,
+    second: &dyn Strategy<'g>,
+    rounds: usize,
+    rng: &mut StdRng,
+) -> Pairing {
+    let mut pairing = Pairing {
+        first: first.name(),
+        second: second.name(),
+        wins: 0,
+        draws: 0,
+        losses: 0,
+        first_score: 0,
+        second_score: 0,
+    };
+    let mut first_history = Vec::with_capacity(rounds);
+    let mut second_history = Vec::with_capacity(rounds);
+
+    for _ in 0..rounds {
+        let a = first.choose(&first_history, rng);
+        let b = second.choose(&second_history, rng);
+
+        match a.outcome(&b) {
+            Outcome::Win => pairing.wins += 1,
+            Outcome::Draw => pairing.draws += 1,
+            Outcome::Loss => pairing.losses += 1,
+        }
+        pairing.first_score += a.play(&b);
+        pairing.second_score += b.play(&a);
+
+        first_history.push(Turn { own: a, other: b });
+        second_history.push(Turn { own: b, other: a });
+    }
+
+    pairing
+}
+
+// every bot against every other one, pairings played in order from one seed
+pub fn round_robin(bots: &[Box<dyn Strategy<'_> + '_>], rounds: usize, seed: u64) -> Vec<Pairing> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut pairings = Vec::new();
+
+    for (i, first) in bots.iter().enumerate() {
+        for second in &bots[i + 1..] {
+            pairings.push(play(first.as_ref(), second.as_ref(), rounds, &mut rng));
+        }
+    }
+
+    pairings
+}
+
+pub fn print(pairings: &[Pairing]) {
+    println!(
+        "{:<16}  {:<16}  {:>6} {:>6} {:>6}  {:>8} {:>8}",
+        "first", "second", "wins", "draws", "losses", "score", "score"
+    );
+    for p in pairings {
+        println!(
+            "{:<16}  {:<16}  {:>6} {:>6} {:>6}  {:>8} {:>8}",
+            p.first, p.second, p.wins, p.draws, p.losses, p.first_score, p.second_score
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_beat_a_fixed_move() {
+        let game = Game::classic();
+        let rock = game.opponent_move("A").unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let pairing = play(&Fixed(rock), &FrequencyCounter(&game), 100, &mut rng);
+        assert!(pairing.losses >= 99);
+        assert_eq!(pairing.wins + pairing.draws + pairing.losses, 100);
+
+        let moves: Vec<Move> = game.moves().collect();
+        let pairing = play(&Fixed(rock), &CopyLast(moves), 100, &mut rng);
+        assert!(pairing.draws >= 99);
+    }
+
+    #[test]
+    fn tournaments_replay_from_their_seed() {
+        let game = Game::classic();
+        let bots = bots(&game, Vec::new());
+
+        let pairings = round_robin(&bots, 50, 7);
+        assert_eq!(pairings.len(), bots.len() * (bots.len() - 1) / 2);
+        assert_eq!(pairings, round_robin(&bots, 50, 7));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::priority_of;
+
+// A set of item types, one bit per priority, so bits 1 to 52 of the mask.
+// Items must be ASCII letters.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn insert(&mut self, item: u8) {
+        self.0 |= 1 << priority_of(item);
+    }
+
+    pub fn contains(self, item: u8) -> bool {
+        self.0 & (1 << priority_of(item)) != 0
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    // the priorities of the items in the set, lowest first
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        let mut mask = self.0;
+        std::iter::from_fn(move || {
+            let priority = mask.trailing_zeros();
+            mask &= mask.checked_sub(1)?;
+            Some(priority)
+        })
+    }
+
+    // the items in the set, lowest priority first
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        self.priorities().map(item_of)
+    }
+}
+
+impl FromIterator<u8> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(items: I) -> Self {
+        let mut set = ItemSet::EMPTY;
+        for item in items {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl From<&[u8]> for ItemSet {
+    fn from(items: &[u8]) -> Self {
+        items.iter().copied().collect()
+    }
+}
+
+pub fn item_of(priority: u32) -> u8 {
+    match priority {
+        1..=26 => b'a' + (priority - 1) as u8,
+        _ => b'A' + (priority - 27) as u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn one_bit_per_item() {
+        let set = ItemSet::from(&b"vJrwpWtwJgWr"[..]);
+
+        assert_eq!(set.len(), 8);
+        assert!(set.contains(b'J') && !set.contains(b'j'));
+        assert_eq!(set.iter().collect::<Vec<u8>>(), b"gprtvwJW");
+        assert_eq!(
+            ItemSet::from(&b"zZ"[..]).priorities().collect::<Vec<_>>(),
+            [26, 52]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn matches_hash_sets(a in "[a-zA-Z]{0,30}", b in "[a-zA-Z]{0,30}") {
+            let (hash_a, hash_b): (HashSet<u8>, HashSet<u8>) =
+                (a.bytes().collect(), b.bytes().collect());
+            let (set_a, set_b) = (ItemSet::from(a.as_bytes()), ItemSet::from(b.as_bytes()));
+
+            let mut common: Vec<u8> = hash_a.intersection(&hash_b).copied().collect();
+            common.sort_by_key(|&c| priority_of(c));
+            prop_assert_eq!(set_a.intersection(set_b).iter().collect::<Vec<u8>>(), common);
+            prop_assert_eq!(set_a.union(set_b).len() as usize, hash_a.union(&hash_b).count());
+        }
+    }
+}
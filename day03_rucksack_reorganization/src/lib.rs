@@ -0,0 +1,161 @@
+pub mod items;
+pub mod repair;
+pub mod report;
+pub mod validate;
+
+use common::{Answer, ParseError};
+use items::ItemSet;
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    solve_with(lines, Layout::default())
+}
+
+pub fn solve_with(lines: &[String], layout: Layout) -> Result<(Answer, Answer), ParseError> {
+    let rucksacks = parse(lines)?;
+    layout.check(&rucksacks)?;
+
+    Ok((
+        part_1(&rucksacks, layout).into(),
+        part_2(&rucksacks, layout).into(),
+    ))
+}
+
+// Rucksacks split into compartments of equal size, and consecutive rucksacks
+// into groups of equal size.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Layout {
+    pub compartments: usize,
+    pub group: usize,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout {
+            compartments: 2,
+            group: 3,
+        }
+    }
+}
+
+impl Layout {
+    pub fn splits(&self, rucksack: &str) -> bool {
+        self.compartments > 0 && rucksack.len().is_multiple_of(self.compartments)
+    }
+
+    // the items of each compartment, None when they do not split evenly, and
+    // no compartments at all for an empty rucksack
+    pub fn compartments<'r>(
+        &self,
+        rucksack: &'r str,
+    ) -> Option<impl Iterator<Item = ItemSet> + 'r> {
+        let size = rucksack.len() / self.compartments.max(1);
+        self.splits(rucksack)
+            .then(|| rucksack.as_bytes().chunks(size.max(1)).map(ItemSet::from))
+    }
+
+    pub fn check_sizes(&self) -> Result<(), ParseError> {
+        match self.compartments == 0 || self.group == 0 {
+            true => Err(ParseError::new("compartments and groups cannot be empty")),
+            false => Ok(()),
+        }
+    }
+
+    // every rucksack splits into compartments and every group is complete
+    pub fn check(&self, rucksacks: &[String]) -> Result<(), ParseError> {
+        self.check_sizes()?;
+
+        for (i, rucksack) in rucksacks.iter().enumerate() {
+            if !self.splits(rucksack) {
+                return Err(ParseError::at(
+                    i,
+                    format!(
+                        "{} items do not split into {} compartments",
+                        rucksack.len(),
+                        self.compartments
+                    ),
+                ));
+            }
+        }
+
+        match rucksacks.len() % self.group {
+            0 => Ok(()),
+            left => Err(ParseError::at(
+                rucksacks.len() - 1,
+                format!("the last group has {left} of {} rucksacks", self.group),
+            )),
+        }
+    }
+}
+
+// the items found in every one of `sets`
+pub fn shared(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+    sets.into_iter()
+        .reduce(ItemSet::intersection)
+        .unwrap_or_default()
+}
+
+// items are letters only, anything else has no priority
+pub fn parse(lines: &[String]) -> Result<Vec<String>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(
+            |(i, line)| match line.bytes().all(|c| c.is_ascii_alphabetic()) {
+                true => Ok(line.clone()),
+                false => Err(ParseError::at(i, format!("invalid items: {line:?}"))),
+            },
+        )
+        .collect()
+}
+
+fn part_1(rucksacks: &[String], layout: Layout) -> u32 {
+    rucksacks
+        .iter()
+        .filter_map(|rucksack| layout.compartments(rucksack))
+        .map(|compartments| shared(compartments).priorities().sum::<u32>())
+        .sum()
+}
+
+fn part_2(rucksacks: &[String], layout: Layout) -> u32 {
+    rucksacks
+        .chunks_exact(layout.group)
+        .map(|group| {
+            shared(
+                group
+                    .iter()
+                    .map(|rucksack| ItemSet::from(rucksack.as_bytes())),
+            )
+            .priorities()
+            .sum::<u32>()
+        })
+        .sum()
+}
+
+pub fn priority_of(c: u8) -> u32 {
+    if c <= b'z' && c >= b'a' {
+        (1 + c - b'a') as u32
+    } else {
+        (27 + c - b'A') as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rucksacks in prop::collection::vec("[a-zA-Z]{1,40}", 0..30)) {
+            let text = rucksacks.join("\n");
+
+            prop_assert_eq!(parse(&split_lines(&text)), Ok(rucksacks));
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[a-zA-Z0-9 \n]*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+    }
+}
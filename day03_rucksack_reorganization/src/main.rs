@@ -1,52 +1,70 @@
-use std::collections::HashSet;
-use std::io::{self, BufRead};
+use clap::Parser;
+use common::{print_part, read_lines};
+use day03_rucksack_reorganization::{parse, repair, report, solve_with, validate, Layout};
 
-fn main() {
-    let stdin = io::stdin();
-    let lines: Vec<String> = stdin.lock().lines().filter_map(Result::ok).collect();
+#[derive(Parser)]
+struct Args {
+    /// Compartments every rucksack is split into
+    #[arg(long, default_value_t = 2)]
+    compartments: usize,
 
-    part_1(&lines);
-    part_2(&lines);
-}
+    /// Rucksacks in every group
+    #[arg(long, default_value_t = 3)]
+    group: usize,
 
-fn part_1(lines: &Vec<String>) {
-    let mut priorities = 0;
-    for line in lines {
-        let comp1: HashSet<char> = line[..line.len() / 2].chars().into_iter().collect();
-        let comp2: HashSet<char> = line[line.len() / 2..].chars().into_iter().collect();
+    /// List every rucksack and group without exactly one shared item instead of solving
+    #[arg(long)]
+    validate: bool,
 
-        for c in comp1.intersection(&comp2) {
-            let priority = priority_of(*c as u8);
+    /// Print the rucksacks with items swapped so no type is in two compartments instead of solving
+    #[arg(long, conflicts_with = "validate")]
+    repair: bool,
 
-            priorities += priority;
-        }
-    }
+    /// Print how often every item type is carried, misplaced and a badge as CSV instead of solving
+    #[arg(long)]
+    report: bool,
 
-    println!("part 1: {priorities}")
+    /// Print how many rucksacks carry every two badge types as CSV in the report
+    #[arg(long, requires = "report")]
+    cooccurrence: bool,
 }
 
-fn part_2(lines: &Vec<String>) {
-    let mut priorities = 0;
-    for line in lines.chunks(3) {
-        let elf1: HashSet<char> = line[0].chars().collect();
-        let elf2: HashSet<char> = line[1].chars().collect();
-        let elf3: HashSet<char> = line[2].chars().collect();
+fn main() {
+    let args = Args::parse();
+    let layout = Layout {
+        compartments: args.compartments,
+        group: args.group,
+    };
+    let lines = read_lines();
+
+    if args.validate {
+        let violations = validate::validate(&lines, layout).unwrap_or_else(|err| err.exit());
+        validate::print(&violations, layout);
+        return;
+    }
 
-        let first_two = elf1.intersection(&elf2).cloned().collect::<HashSet<char>>();
+    if args.repair {
+        let rucksacks = parse(&lines).unwrap_or_else(|err| err.exit());
+        let repairs = repair::plan(&rucksacks, layout).unwrap_or_else(|err| err.exit());
+        repair::print(&rucksacks, &repairs);
+        return;
+    }
 
-        for c in first_two.intersection(&elf3) {
-            let priority = priority_of(*c as u8);
-            priorities += priority;
+    if args.report {
+        let rucksacks = parse(&lines).unwrap_or_else(|err| err.exit());
+        layout.check(&rucksacks).unwrap_or_else(|err| err.exit());
+        match args.cooccurrence {
+            true => {
+                let (types, counts) = report::badge_cooccurrence(&rucksacks, layout);
+                report::print_cooccurrence_csv(&types, &counts);
+            }
+            false => report::print_csv(&report::item_stats(&rucksacks, layout)),
         }
+        return;
     }
 
-    println!("part 2: {priorities}");
-}
+    let (part_1, part_2) = solve_with(&lines, layout).unwrap_or_else(|err| err.exit());
 
-fn priority_of(c: u8) -> u32 {
-    if c <= b'z' && c >= b'a' {
-        (1 + c - b'a') as u32
-    } else {
-        (27 + c - b'A') as u32
-    }
+    print_part(1, &part_1);
+    print_part(2, &part_2);
 }
@@ -0,0 +1,234 @@
+use crate::{items::ItemSet, Layout};
+use common::ParseError;
+use std::collections::HashMap;
+
+// two positions in the line whose items trade places
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Swap {
+    pub first: usize,
+    pub second: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Repair {
+    pub swaps: Vec<Swap>,
+    // the rucksack once every swap is made
+    pub line: String,
+}
+
+// A repair for every rucksack, None for the ones whose item types cannot be
+// packed into the compartments a type each.
+pub fn plan(rucksacks: &[String], layout: Layout) -> Result<Vec<Option<Repair>>, ParseError> {
+    layout.check_sizes()?;
+
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(i, rucksack)| match layout.compartments(rucksack) {
+            Some(_) => Ok(repair(rucksack, layout.compartments)),
+            None => Err(ParseError::at(
+                i,
+                format!(
+                    "{} items do not split into {} compartments",
+                    rucksack.len(),
+                    layout.compartments
+                ),
+            )),
+        })
+        .collect()
+}
+
+// Swaps items between compartments until every item type is in a single one,
+// moving as few items as possible. With two compartments every swap puts two
+// items in place, so that is also the fewest swaps.
+pub fn repair(rucksack: &str, compartments: usize) -> Option<Repair> {
+    let mut items = rucksack.as_bytes().to_vec();
+    let size = items.len() / compartments;
+    let compartment = |position: usize| position / size.max(1);
+
+    let types: Vec<u8> = ItemSet::from(&items[..]).iter().collect();
+    let counts: Vec<Vec<usize>> = types
+        .iter()
+        .map(|&t| {
+            let mut counts = vec![0; compartments];
+            for (p, _) in items.iter().enumerate().filter(|&(_, &item)| item == t) {
+                counts[compartment(p)] += 1;
+            }
+            counts
+        })
+        .collect();
+
+    let mut search = Search {
+        counts: &counts,
+        memo: HashMap::new(),
+    };
+    let mut room = vec![size; compartments];
+    search.moved(0, &mut room)?;
+
+    // walk the memo back down to find where each type goes
+    let mut owner: HashMap<u8, usize> = HashMap::new();
+    for (t, &item) in types.iter().enumerate() {
+        let moved = search.moved(t, &mut room)?;
+        let total: usize = counts[t].iter().sum();
+
+        for c in 0..compartments {
+            if room[c] < total {
+                continue;
+            }
+            room[c] -= total;
+            let rest = search.moved(t + 1, &mut room);
+            if rest.is_some_and(|rest| rest + total - counts[t][c] == moved) {
+                owner.insert(item, c);
+                break;
+            }
+            room[c] += total;
+        }
+    }
+
+    let misplaced = |items: &[u8], p: usize| owner[&items[p]] != compartment(p);
+    let mut swaps = Vec::new();
+    while let Some(first) = (0..items.len()).find(|&p| misplaced(&items, p)) {
+        let wanted = owner[&items[first]];
+        let candidates: Vec<usize> = (wanted * size..(wanted + 1) * size)
+            .filter(|&q| misplaced(&items, q))
+            .collect();
+        let second = candidates
+            .iter()
+            .copied()
+            .find(|&q| owner[&items[q]] == compartment(first))
+            .or(candidates.first().copied())?;
+
+        items.swap(first, second);
+        swaps.push(Swap { first, second });
+    }
+
+    Some(Repair {
+        swaps,
+        line: String::from_utf8(items).expect("items are letters"),
+    })
+}
+
+struct Search<'a> {
+    // how many items of each type every compartment has
+    counts: &'a [Vec<usize>],
+    // fewest items moved to place the types from the key's index on, given
+    // the room left in each compartment
+    memo: HashMap<(usize, Vec<usize>), Option<usize>>,
+}
+
+impl Search<'_> {
+    fn moved(&mut self, t: usize, room: &mut Vec<usize>) -> Option<usize> {
+        let Some(counts) = self.counts.get(t) else {
+            return Some(0);
+        };
+        if let Some(&known) = self.memo.get(&(t, room.clone())) {
+            return known;
+        }
+
+        let total: usize = counts.iter().sum();
+        let mut best = None;
+        for c in 0..room.len() {
+            if room[c] < total {
+                continue;
+            }
+            room[c] -= total;
+            if let Some(rest) = self.moved(t + 1, room) {
+                let moved = rest + total - counts[c];
+                if best.is_none_or(|b| moved < b) {
+                    best = Some(moved);
+                }
+            }
+            room[c] += total;
+        }
+
+        self.memo.insert((t, room.clone()), best);
+        best
+    }
+}
+
+// corrected rucksacks on stdout in the input's format, the plan on stderr
+pub fn print(rucksacks: &[String], repairs: &[Option<Repair>]) {
+    let mut swapped = 0;
+    let mut infeasible = 0;
+
+    for (i, (rucksack, repair)) in rucksacks.iter().zip(repairs).enumerate() {
+        match repair {
+            Some(repair) => {
+                println!("{}", repair.line);
+                if !repair.swaps.is_empty() {
+                    let swaps: Vec<String> = repair
+                        .swaps
+                        .iter()
+                        .map(|s| {
+                            let bytes = rucksack.as_bytes();
+                            format!(
+                                "{}@{}<->{}@{}",
+                                bytes[s.first] as char,
+                                s.first + 1,
+                                bytes[s.second] as char,
+                                s.second + 1
+                            )
+                        })
+                        .collect();
+                    eprintln!("line {}: {}", i + 1, swaps.join(" "));
+                }
+                swapped += repair.swaps.len();
+            }
+            None => {
+                println!("{rucksack}");
+                eprintln!("line {}: cannot be repaired", i + 1);
+                infeasible += 1;
+            }
+        }
+    }
+
+    eprintln!("{swapped} swaps, {infeasible} rucksacks cannot be repaired");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared;
+    use proptest::prelude::*;
+
+    #[test]
+    fn swaps_mixed_items_apart() {
+        assert_eq!(repair("aabb", 2).unwrap().swaps, []);
+        assert_eq!(repair("abab", 2).unwrap().line, "aabb");
+        assert_eq!(repair("aaab", 2), None);
+        assert_eq!(repair("abcabc", 3).unwrap().swaps.len(), 2);
+    }
+
+    proptest! {
+        #[test]
+        fn swaps_are_fewest(
+            items in (1..8usize).prop_flat_map(|n| prop::collection::vec(b'a'..=b'd', 2 * n))
+        ) {
+            let rucksack = String::from_utf8(items).unwrap();
+            let size = rucksack.len() / 2;
+
+            // every way of giving the four types to the two compartments
+            let fewest = (0..16u32)
+                .filter_map(|owners| {
+                    let owner = |item: u8| (owners >> (item - b'a')) as usize & 1;
+                    let first = rucksack.bytes().filter(|&item| owner(item) == 0).count();
+                    let moved = rucksack
+                        .bytes()
+                        .enumerate()
+                        .filter(|&(p, item)| owner(item) != p / size)
+                        .count();
+                    (first == size).then_some(moved)
+                })
+                .min();
+
+            match repair(&rucksack, 2) {
+                Some(repair) => {
+                    let compartments = Layout::default().compartments(&repair.line).unwrap();
+                    prop_assert!(shared(compartments).is_empty());
+                    prop_assert_eq!(Some(2 * repair.swaps.len()), fewest);
+                }
+                None => prop_assert_eq!(fewest, None),
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    items::{item_of, ItemSet},
+    shared, Layout,
+};
+
+// how often an item type turns up across all the rucksacks
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ItemStats {
+    pub item: u8,
+    pub priority: u32,
+    // rucksacks with at least one of the item
+    pub rucksacks: usize,
+    // rucksacks where every compartment has the item
+    pub misplaced: usize,
+    // groups where every rucksack has the item
+    pub badges: usize,
+}
+
+// One row per item type, in priority order. The rucksacks must fit the
+// layout, as checked by `Layout::check`.
+pub fn item_stats(rucksacks: &[String], layout: Layout) -> Vec<ItemStats> {
+    let mut stats: Vec<ItemStats> = (1..=52)
+        .map(|priority| ItemStats {
+            item: item_of(priority),
+            priority,
+            rucksacks: 0,
+            misplaced: 0,
+            badges: 0,
+        })
+        .collect();
+    let mut count = |set: ItemSet, field: fn(&mut ItemStats) -> &mut usize| {
+        for priority in set.priorities() {
+            *field(&mut stats[priority as usize - 1]) += 1;
+        }
+    };
+
+    for rucksack in rucksacks {
+        count(ItemSet::from(rucksack.as_bytes()), |s| &mut s.rucksacks);
+        if let Some(compartments) = layout.compartments(rucksack) {
+            count(shared(compartments), |s| &mut s.misplaced);
+        }
+    }
+    for badges in badges(rucksacks, layout) {
+        count(badges, |s| &mut s.badges);
+    }
+
+    stats
+}
+
+fn badges(rucksacks: &[String], layout: Layout) -> impl Iterator<Item = ItemSet> + '_ {
+    rucksacks.chunks_exact(layout.group).map(|group| {
+        shared(
+            group
+                .iter()
+                .map(|rucksack| ItemSet::from(rucksack.as_bytes())),
+        )
+    })
+}
+
+// The item types that are ever a badge, and for every two of them the number
+// of rucksacks carrying both. The diagonal counts the rucksacks carrying one.
+pub fn badge_cooccurrence(rucksacks: &[String], layout: Layout) -> (Vec<u8>, Vec<Vec<usize>>) {
+    let all = badges(rucksacks, layout).fold(ItemSet::EMPTY, ItemSet::union);
+    let types: Vec<u8> = all.iter().collect();
+    let mut counts = vec![vec![0; types.len()]; types.len()];
+
+    for rucksack in rucksacks {
+        let items = ItemSet::from(rucksack.as_bytes());
+        for (i, &a) in types.iter().enumerate() {
+            for (j, &b) in types.iter().enumerate() {
+                if items.contains(a) && items.contains(b) {
+                    counts[i][j] += 1;
+                }
+            }
+        }
+    }
+
+    (types, counts)
+}
+
+pub fn print_csv(stats: &[ItemStats]) {
+    println!("item,priority,rucksacks,misplaced,badges");
+    for s in stats {
+        println!(
+            "{},{},{},{},{}",
+            s.item as char, s.priority, s.rucksacks, s.misplaced, s.badges
+        );
+    }
+}
+
+pub fn print_cooccurrence_csv(types: &[u8], counts: &[Vec<usize>]) {
+    let header: Vec<String> = types.iter().map(|&t| (t as char).to_string()).collect();
+    println!("badge,{}", header.join(","));
+    for (&t, row) in types.iter().zip(counts) {
+        let row: Vec<String> = row.iter().map(usize::to_string).collect();
+        println!("{},{}", t as char, row.join(","));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+
+    #[test]
+    fn example_counts() {
+        let rucksacks = split_lines(include_str!("../example.txt"));
+        let stats = item_stats(&rucksacks, Layout::default());
+        let row = |item: u8| stats.iter().find(|s| s.item == item).unwrap();
+
+        // p, L, P, v, t and s are misplaced, r and Z are the badges
+        assert_eq!(stats.len(), 52);
+        assert_eq!(stats.iter().map(|s| s.misplaced).sum::<usize>(), 6);
+        assert_eq!((row(b'r').badges, row(b'Z').badges), (1, 1));
+        assert_eq!(row(b'r').rucksacks, 4);
+
+        let (types, counts) = badge_cooccurrence(&rucksacks, Layout::default());
+        assert_eq!(types, b"rZ");
+        assert_eq!(counts[0][0], row(b'r').rucksacks);
+        assert_eq!(counts[0][1], counts[1][0]);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{items::ItemSet, shared, Layout};
+use common::ParseError;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Problem {
+    // characters that are not items
+    InvalidItems,
+    // more items than split evenly into the compartments
+    Uneven,
+    // the items every compartment has, when that is not exactly one
+    Shared(ItemSet),
+    // the items every rucksack of the group has, when that is not exactly one
+    Badges(ItemSet),
+    // rucksacks left over after the last complete group
+    PartialGroup,
+}
+
+// a problem with the rucksacks from line index `first` to `last`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Violation {
+    pub first: usize,
+    pub last: usize,
+    pub problem: Problem,
+}
+
+// Every rucksack and group that breaks the "exactly one shared item" rule,
+// in the order of their first line. Groups with an invalid rucksack are only
+// reported through that rucksack.
+pub fn validate(lines: &[String], layout: Layout) -> Result<Vec<Violation>, ParseError> {
+    layout.check_sizes()?;
+
+    let valid: Vec<bool> = lines
+        .iter()
+        .map(|line| line.bytes().all(|c| c.is_ascii_alphabetic()))
+        .collect();
+    let mut violations = Vec::new();
+    let mut report = |first, last, problem| {
+        violations.push(Violation {
+            first,
+            last,
+            problem,
+        })
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        if !valid[i] {
+            report(i, i, Problem::InvalidItems);
+            continue;
+        }
+
+        match layout.compartments(line).map(shared) {
+            None => report(i, i, Problem::Uneven),
+            Some(items) if items.len() != 1 => report(i, i, Problem::Shared(items)),
+            Some(_) => {}
+        }
+    }
+
+    for (g, group) in lines.chunks(layout.group).enumerate() {
+        let first = g * layout.group;
+        let last = first + group.len() - 1;
+
+        if group.len() < layout.group {
+            report(first, last, Problem::PartialGroup);
+        } else if valid[first..=last].iter().all(|&v| v) {
+            let badges = shared(group.iter().map(|line| ItemSet::from(line.as_bytes())));
+            if badges.len() != 1 {
+                report(first, last, Problem::Badges(badges));
+            }
+        }
+    }
+
+    violations.sort_by_key(|v| v.first);
+    Ok(violations)
+}
+
+fn items(set: ItemSet) -> String {
+    let items: Vec<String> = set.iter().map(|c| (c as char).to_string()).collect();
+    items.join(", ")
+}
+
+pub fn print(violations: &[Violation], layout: Layout) {
+    for v in violations {
+        let lines = match v.first == v.last {
+            true => format!("line {}", v.first + 1),
+            false => format!("lines {}-{}", v.first + 1, v.last + 1),
+        };
+        let problem = match v.problem {
+            Problem::InvalidItems => String::from("invalid items"),
+            Problem::Uneven => format!(
+                "items do not split into {} compartments",
+                layout.compartments
+            ),
+            Problem::Shared(set) if set.is_empty() => {
+                String::from("no item shared by every compartment")
+            }
+            Problem::Shared(set) => format!(
+                "{} items shared by every compartment: {}",
+                set.len(),
+                items(set)
+            ),
+            Problem::Badges(set) if set.is_empty() => String::from("no badge in the group"),
+            Problem::Badges(set) => format!("{} badges in the group: {}", set.len(), items(set)),
+            Problem::PartialGroup => format!(
+                "the last group has {} of {} rucksacks",
+                v.last - v.first + 1,
+                layout.group
+            ),
+        };
+        println!("{lines}: {problem}");
+    }
+
+    println!("{} problems", violations.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+
+    #[test]
+    fn reports_every_broken_rule() {
+        let lines = split_lines("abcabc\naBcd\nab1\naa\nzz\nAbA");
+        let violations = validate(&lines, Layout::default()).unwrap();
+        let problems: Vec<(usize, usize, Problem)> = violations
+            .iter()
+            .map(|v| (v.first, v.last, v.problem))
+            .collect();
+
+        let abc = ItemSet::from(&b"abc"[..]);
+        assert_eq!(
+            problems,
+            [
+                (0, 0, Problem::Shared(abc)),
+                (1, 1, Problem::Shared(ItemSet::EMPTY)),
+                (2, 2, Problem::InvalidItems),
+                (3, 5, Problem::Badges(ItemSet::EMPTY)),
+                (5, 5, Problem::Uneven),
+            ]
+        );
+    }
+
+    #[test]
+    fn example_follows_the_rules() {
+        let lines = split_lines(include_str!("../example.txt"));
+
+        assert_eq!(validate(&lines, Layout::default()), Ok(Vec::new()));
+        assert!(
+            validate(&lines[..5], Layout::default()).unwrap()[0].problem == Problem::PartialGroup
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Assignment, Elf};
+use geometry::{Interval, IntervalSet};
+use std::collections::BTreeSet;
+
+// a run of sections covered by the same elves
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub sections: Assignment,
+    pub elves: Vec<Elf>,
+}
+
+impl Segment {
+    pub fn depth(&self) -> usize {
+        self.elves.len()
+    }
+}
+
+// Every run of sections that some elf covers, from the lowest section up,
+// found by sweeping over the starts and ends of the assignments.
+pub fn sweep(elves: &[(Elf, Assignment)]) -> Vec<Segment> {
+    // (section, elf index, whether the elf starts there), ends being the
+    // section after the assignment, which does not exist past u64::MAX
+    let mut events: Vec<(u64, usize, bool)> = Vec::with_capacity(2 * elves.len());
+    for (i, (_, assignment)) in elves.iter().enumerate() {
+        if assignment.is_empty() {
+            continue;
+        }
+        events.push((assignment.start, i, true));
+        if let Some(end) = assignment.end_exclusive() {
+            events.push((end, i, false));
+        }
+    }
+    events.sort();
+
+    let mut segments = Vec::new();
+    let mut active: BTreeSet<usize> = BTreeSet::new();
+    let mut events = events.into_iter().peekable();
+
+    while let Some((section, i, starts)) = events.next() {
+        match starts {
+            true => active.insert(i),
+            false => active.remove(&i),
+        };
+        if events.peek().is_some_and(|&(next, _, _)| next == section) || active.is_empty() {
+            continue;
+        }
+
+        let sections = match events.peek() {
+            Some(&(next, _, _)) => Interval::half_open(section, next),
+            None => Interval::closed(section, u64::MAX),
+        };
+        segments.push(Segment {
+            sections,
+            elves: active.iter().map(|&i| elves[i].0).collect(),
+        });
+    }
+
+    segments
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage {
+    // sections between the lowest and the highest assigned that nobody covers
+    pub uncovered: IntervalSet<u64>,
+    // segments covered by more elves than the limit
+    pub crowded: Vec<Segment>,
+    pub max_depth: usize,
+    // the segments covered by `max_depth` elves
+    pub deepest: Vec<Segment>,
+}
+
+pub fn analyse(elves: &[(Elf, Assignment)], limit: usize) -> Coverage {
+    let segments = sweep(elves);
+
+    let covered: IntervalSet<u64> = segments.iter().map(|s| s.sections).collect();
+    let uncovered = match (covered.intervals().first(), covered.intervals().last()) {
+        (Some(first), Some(last)) => covered.gaps(Interval::closed(first.start, last.end)),
+        _ => IntervalSet::new(),
+    };
+
+    let max_depth = segments.iter().map(Segment::depth).max().unwrap_or(0);
+    let with_depth = |keep: &dyn Fn(usize) -> bool| -> Vec<Segment> {
+        segments
+            .iter()
+            .filter(|s| keep(s.depth()))
+            .cloned()
+            .collect()
+    };
+
+    Coverage {
+        uncovered,
+        crowded: with_depth(&|depth| depth > limit),
+        max_depth,
+        deepest: with_depth(&|depth| depth == max_depth),
+    }
+}
+
+fn sections(interval: &Assignment) -> String {
+    match interval.start == interval.end {
+        true => interval.start.to_string(),
+        false => format!("{}-{}", interval.start, interval.end),
+    }
+}
+
+fn print_segments(segments: &[Segment]) {
+    for segment in segments {
+        let elves: Vec<String> = segment.elves.iter().map(Elf::to_string).collect();
+        println!(
+            "  {} by {} elves: {}",
+            sections(&segment.sections),
+            segment.depth(),
+            elves.join(" ")
+        );
+    }
+}
+
+pub fn print(coverage: &Coverage, limit: usize) {
+    println!(
+        "covered by nobody: {} sections in {} ranges",
+        coverage.uncovered.len(),
+        coverage.uncovered.intervals().len()
+    );
+    for interval in coverage.uncovered.intervals() {
+        println!("  {}", sections(interval));
+    }
+
+    let crowded: u128 = coverage.crowded.iter().map(|s| s.sections.len()).sum();
+    println!(
+        "covered by more than {limit} elves: {crowded} sections in {} segments",
+        coverage.crowded.len()
+    );
+    print_segments(&coverage.crowded);
+
+    println!("maximal depth: {}", coverage.max_depth);
+    print_segments(&coverage.deepest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{elves, parse};
+    use common::split_lines;
+
+    #[test]
+    fn example_coverage() {
+        let groups = parse(&split_lines(include_str!("../example.txt"))).unwrap();
+        let coverage = analyse(&elves(&groups), 3);
+
+        assert_eq!(coverage.uncovered, IntervalSet::new());
+        assert_eq!(coverage.max_depth, 8);
+        assert_eq!(coverage.deepest[0].sections, Interval::closed(6, 6));
+        assert!(coverage.crowded.iter().all(|s| s.depth() > 3));
+    }
+
+    #[test]
+    fn reaches_the_last_section() {
+        let elf = |line| Elf { line, position: 0 };
+        let elves = [
+            (elf(0), Interval::closed(u64::MAX - 1, u64::MAX)),
+            (elf(1), Interval::closed(0, 1)),
+            (elf(2), Interval::closed(u64::MAX, u64::MAX)),
+        ];
+        let coverage = analyse(&elves, 1);
+
+        assert_eq!(
+            coverage.uncovered.intervals(),
+            [Interval::closed(2, u64::MAX - 2)]
+        );
+        assert_eq!(coverage.max_depth, 2);
+        assert_eq!(coverage.deepest[0].elves, [elf(0), elf(2)]);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::Assignment;
+use geometry::Interval;
+
+// An elf whose assignment covers everyone else's in the group, the first one
+// when several do.
+pub fn covering_elf(group: &[Assignment]) -> Option<usize> {
+    let start = group.iter().map(|a| a.start).min()?;
+    let end = group.iter().map(|a| a.end).max()?;
+
+    group.iter().position(|a| a.start == start && a.end == end)
+}
+
+// the sections every elf of the group is assigned, empty when there are none
+pub fn common_sections(group: &[Assignment]) -> Assignment {
+    group
+        .iter()
+        .fold(Interval::closed(u64::MIN, u64::MAX), |common, a| {
+            common.intersection(a)
+        })
+}
+
+// Pairs of elves in the group whose assignments overlap. Every pair overlaps
+// unless one of them ends before the other starts, and the assignments that
+// start after a given end can be counted by a binary search.
+pub fn overlapping_pairs(group: &[Assignment]) -> u64 {
+    let mut starts: Vec<u64> = group.iter().map(|a| a.start).collect();
+    starts.sort_unstable();
+
+    let n = group.len() as u64;
+    let apart: u64 = group
+        .iter()
+        .map(|a| (starts.len() - starts.partition_point(|&s| s <= a.end)) as u64)
+        .sum();
+
+    n * n.saturating_sub(1) / 2 - apart
+}
+
+pub fn print(groups: &[Vec<Assignment>]) {
+    println!("line  elves  covering  common sections  overlapping pairs");
+    for (i, group) in groups.iter().enumerate() {
+        let covering = match covering_elf(group) {
+            Some(elf) => (elf + 1).to_string(),
+            None => String::from("-"),
+        };
+        let common = common_sections(group);
+        let common = match common.is_empty() {
+            true => String::from("-"),
+            false => format!("{}-{}", common.start, common.end),
+        };
+        println!(
+            "{:>4}  {:>5}  {:>8}  {:>15}  {:>17}",
+            i + 1,
+            group.len(),
+            covering,
+            common,
+            overlapping_pairs(group)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn assignment() -> impl Strategy<Value = Assignment> {
+        (0..50u64, 0..50u64).prop_map(|(a, b)| Interval::closed(a.min(b), a.max(b)))
+    }
+
+    #[test]
+    fn group_queries() {
+        let group = [
+            Interval::closed(2, 8),
+            Interval::closed(3, 7),
+            Interval::closed(6, 9),
+        ];
+
+        assert_eq!(covering_elf(&group), None);
+        assert_eq!(covering_elf(&group[..2]), Some(0));
+        assert_eq!(common_sections(&group), Interval::closed(6, 7));
+        assert_eq!(overlapping_pairs(&group), 3);
+        assert_eq!(overlapping_pairs(&[]), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn overlapping_pairs_match_every_pair(group in prop::collection::vec(assignment(), 0..20)) {
+            let mut expected = 0;
+            for (i, a) in group.iter().enumerate() {
+                expected += group[i + 1..].iter().filter(|b| a.overlaps(b)).count() as u64;
+            }
+
+            prop_assert_eq!(overlapping_pairs(&group), expected);
+        }
+    }
+}
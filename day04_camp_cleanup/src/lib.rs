@@ -0,0 +1,117 @@
+pub mod coverage;
+pub mod group;
+pub mod reassign;
+
+use common::{Answer, ParseError};
+use geometry::Interval;
+use std::fmt;
+
+// the sections an elf is assigned
+pub type Assignment = Interval<u64>;
+
+// a start and an end section, both inclusive
+fn parse_interval(s: &str) -> Option<Assignment> {
+    let (start, end) = s.split_once('-')?;
+    let (start, end) = (start.parse().ok()?, end.parse().ok()?);
+
+    (start <= end).then_some(Interval::closed(start, end))
+}
+
+// an elf by the line of its assignment and its place on the line, from 0
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf {
+    pub line: usize,
+    pub position: usize,
+}
+
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.line + 1, self.position + 1)
+    }
+}
+
+// every elf of every group with its assignment
+pub fn elves(groups: &[Vec<Assignment>]) -> Vec<(Elf, Assignment)> {
+    groups
+        .iter()
+        .enumerate()
+        .flat_map(|(line, group)| {
+            group
+                .iter()
+                .copied()
+                .enumerate()
+                .map(move |(position, assignment)| (Elf { line, position }, assignment))
+        })
+        .collect()
+}
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let groups = parse(lines)?;
+
+    Ok((part_1(&groups).into(), part_2(&groups).into()))
+}
+
+// one group of elves per line, their assignments separated by commas
+pub fn parse(lines: &[String]) -> Result<Vec<Vec<Assignment>>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.split(',')
+                .map(parse_interval)
+                .collect::<Option<Vec<Assignment>>>()
+                .ok_or_else(|| ParseError::at(i, format!("invalid assignments: {line:?}")))
+        })
+        .collect()
+}
+
+fn part_1(groups: &[Vec<Assignment>]) -> usize {
+    groups
+        .iter()
+        .filter(|group| group::covering_elf(group).is_some())
+        .count()
+}
+
+fn part_2(groups: &[Vec<Assignment>]) -> usize {
+    groups
+        .iter()
+        .filter(|group| !group::common_sections(group).is_empty())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    fn interval() -> impl Strategy<Value = Assignment> {
+        (any::<u64>(), any::<u64>()).prop_map(|(a, b)| Interval::closed(a.min(b), a.max(b)))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(
+            groups in prop::collection::vec(prop::collection::vec(interval(), 1..6), 0..30)
+        ) {
+            let text = groups
+                .iter()
+                .map(|group| {
+                    group
+                        .iter()
+                        .map(|i| format!("{}-{}", i.start, i.end))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            prop_assert_eq!(parse(&split_lines(&text)), Ok(groups));
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9,\n-]*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+    }
+}
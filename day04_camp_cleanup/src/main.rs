@@ -1,64 +1,53 @@
-use std::io::{stdin, BufRead};
-
-#[derive(Debug, Copy, Clone)]
-struct Interval {
-    start: u32,
-    end: u32,
+use clap::Parser;
+use common::{print_part, read_lines};
+use day04_camp_cleanup::{coverage, elves, group, parse, reassign, solve};
+
+#[derive(Parser)]
+struct Args {
+    /// Report how all the assignments together cover the sections instead of solving
+    #[arg(long)]
+    coverage: bool,
+
+    /// Report sections covered by more than this many elves in the coverage
+    #[arg(long, default_value_t = 2)]
+    crowded: usize,
+
+    /// Report who covers everyone, the common sections and the overlapping pairs of every line
+    #[arg(long)]
+    groups: bool,
+
+    /// Print the assignments rewritten so no two elves of a line overlap instead of solving
+    #[arg(long)]
+    reassign: bool,
 }
 
-impl Interval {
-    fn parse(s: &str) -> Self {
-        let numbers = s
-            .split('-')
-            .map(|s| s.parse::<u32>())
-            .filter_map(Result::ok)
-            .collect::<Vec<u32>>();
-
-        Interval {
-            start: numbers[0],
-            end: numbers[1],
-        }
+fn main() {
+    let args = Args::parse();
+    let lines = read_lines();
+
+    if args.coverage {
+        let groups = parse(&lines).unwrap_or_else(|err| err.exit());
+        coverage::print(
+            &coverage::analyse(&elves(&groups), args.crowded),
+            args.crowded,
+        );
+        return;
     }
 
-    fn contains(&self, other: &Interval) -> bool {
-        self.start <= other.start && self.end >= other.end
+    if args.groups {
+        group::print(&parse(&lines).unwrap_or_else(|err| err.exit()));
+        return;
     }
 
-    fn overlaps(&self, other: &Interval) -> bool {
-        (self.start..=self.end).contains(&other.start)
-            || (self.start..=self.end).contains(&other.end)
-            || other.contains(self)
+    if args.reassign {
+        let groups = parse(&lines).unwrap_or_else(|err| err.exit());
+        let reassigned: Vec<_> = groups.iter().map(|g| reassign::reassign(g)).collect();
+        reassign::print(&groups, &reassigned);
+        return;
     }
-}
-
-fn main() {
-    let lines = stdin().lock().lines().filter_map(Result::ok);
-
-    let pairs: Vec<(Interval, Interval)> = lines
-        .map(|line| {
-            let intervals = line
-                .split(',')
-                .map(Interval::parse)
-                .collect::<Vec<Interval>>();
-            (intervals[0], intervals[1])
-        })
-        .collect();
-
-    part_1(&pairs);
-    part_2(&pairs);
-}
-
-fn part_1(pairs: &Vec<(Interval, Interval)>) {
-    let complete_overlaps = pairs
-        .iter()
-        .filter(|(i1, i2)| i1.contains(&i2) || i2.contains(&i1))
-        .count();
-
-    println!("part 1: {complete_overlaps}");
-}
 
-fn part_2(pairs: &Vec<(Interval, Interval)>) {
-    let overlaps = pairs.iter().filter(|(i1, i2)| i1.overlaps(&i2)).count();
+    let (part_1, part_2) = solve(&lines).unwrap_or_else(|err| err.exit());
 
-    println!("part 1: {overlaps}");
+    print_part(1, &part_1);
+    print_part(2, &part_2);
 }
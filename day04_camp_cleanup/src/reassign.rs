@@ -0,0 +1,136 @@
+use crate::Assignment;
+use geometry::{Interval, IntervalSet};
+
+// sections assigned more than once, counted once for every extra elf
+pub fn overlap(group: &[Assignment]) -> u128 {
+    let union: IntervalSet<u64> = group.iter().copied().collect();
+    group.iter().map(Interval::len).sum::<u128>() - union.len()
+}
+
+// pairs of elves where one covers the other
+pub fn containments(group: &[Assignment]) -> usize {
+    let mut count = 0;
+    for (i, a) in group.iter().enumerate() {
+        count += group[i + 1..]
+            .iter()
+            .filter(|b| a.covers(b) || b.covers(a))
+            .count();
+    }
+    count
+}
+
+// New assignments for the group, in the same order, that cover the same
+// sections without any overlap, so that nobody covers anybody else either.
+// Each elf stays in the run of sections its assignment was in, and starts
+// where it used to whenever that leaves room for the elves after it. None
+// when a run has fewer sections than elves.
+pub fn reassign(group: &[Assignment]) -> Option<Vec<Assignment>> {
+    let union: IntervalSet<u64> = group.iter().copied().collect();
+    let mut result = group.to_vec();
+
+    for run in union.intervals() {
+        let mut elves: Vec<usize> = (0..group.len())
+            .filter(|&e| run.covers(&group[e]))
+            .collect();
+        elves.sort_by_key(|&e| (group[e].start, group[e].end));
+
+        if (elves.len() as u128) > run.len() {
+            return None;
+        }
+
+        let mut start = run.start;
+        for (j, &e) in elves.iter().enumerate() {
+            let end = match elves.get(j + 1) {
+                Some(&next) => {
+                    // the latest start for the next elf that leaves a section
+                    // for each elf after it
+                    let latest = run.end - (elves.len() - j - 2) as u64;
+                    group[next].start.clamp(start + 1, latest) - 1
+                }
+                None => run.end,
+            };
+            result[e] = Interval::closed(start, end);
+            start = end.saturating_add(1);
+        }
+    }
+
+    Some(result)
+}
+
+// the rewritten assignments on stdout in the input's format, what changed on
+// stderr
+pub fn print(groups: &[Vec<Assignment>], reassigned: &[Option<Vec<Assignment>>]) {
+    let (mut before, mut after) = ((0, 0), (0, 0));
+    let mut infeasible = 0;
+
+    for (i, (group, new)) in groups.iter().zip(reassigned).enumerate() {
+        let written = new.as_ref().unwrap_or(group);
+        let ranges: Vec<String> = written
+            .iter()
+            .map(|a| format!("{}-{}", a.start, a.end))
+            .collect();
+        println!("{}", ranges.join(","));
+
+        if new.is_none() {
+            eprintln!("line {}: more elves than sections to share", i + 1);
+            infeasible += 1;
+        }
+        before.0 += overlap(group);
+        before.1 += containments(group);
+        after.0 += overlap(written);
+        after.1 += containments(written);
+    }
+
+    eprintln!("overlapping sections: {} -> {}", before.0, after.0);
+    eprintln!("full containments: {} -> {}", before.1, after.1);
+    eprintln!("{infeasible} lines left as they were");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn splits_contained_pairs() {
+        let group = [Interval::closed(2, 8), Interval::closed(3, 7)];
+        assert_eq!(
+            reassign(&group),
+            Some(vec![Interval::closed(2, 2), Interval::closed(3, 8)])
+        );
+
+        assert_eq!(
+            reassign(&[Interval::closed(6, 6), Interval::closed(6, 6)]),
+            None
+        );
+        let top = Interval::closed(u64::MAX - 1, u64::MAX);
+        assert_eq!(reassign(&[top, top]).map(|g| overlap(&g)), Some(0));
+    }
+
+    proptest! {
+        #[test]
+        fn reassignments_cover_the_same_sections(
+            group in prop::collection::vec(
+                (0..30u64, 0..30u64).prop_map(|(a, b)| Interval::closed(a.min(b), a.max(b))),
+                1..8,
+            )
+        ) {
+            let union: IntervalSet<u64> = group.iter().copied().collect();
+
+            match reassign(&group) {
+                Some(new) => {
+                    prop_assert_eq!(new.len(), group.len());
+                    prop_assert_eq!(new.iter().copied().collect::<IntervalSet<u64>>(), union);
+                    prop_assert_eq!(overlap(&new), 0);
+                    prop_assert_eq!(containments(&new), 0);
+                }
+                None => {
+                    let crowded = union.intervals().iter().any(|run| {
+                        group.iter().filter(|a| run.covers(a)).count() as u128 > run.len()
+                    });
+                    prop_assert!(crowded);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::{collections::HashMap, process};
+
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::{parse, part_1, part_2};
+
+// Crate letters are permuted consistently across the drawing, so the
+// top-of-stack answers come out permuted the same way.
+pub fn run(lines: &[String], seed: Option<u64>) {
+    let seed = seed.unwrap_or_else(rand::random);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut shuffled: Vec<char> = ('A'..='Z').collect();
+    shuffled.shuffle(&mut rng);
+    let letters: HashMap<char, char> = ('A'..='Z').zip(shuffled).collect();
+
+    let anonymised = anonymise(lines, &letters);
+
+    let expected = solve(lines);
+    let expected = (
+        relabel(&expected.0, &letters),
+        relabel(&expected.1, &letters),
+    );
+    let actual = solve(&anonymised);
+
+    if expected != actual {
+        eprintln!("anonymisation changed the answers: expected {expected:?}, got {actual:?}");
+        process::exit(1);
+    }
+
+    for line in anonymised {
+        println!("{line}");
+    }
+    eprintln!("seed {seed}: answers preserved up to crate relabelling");
+}
+
+fn anonymise(lines: &[String], letters: &HashMap<char, char>) -> Vec<String> {
+    lines
+        .iter()
+        .map(|line| match line.contains('[') {
+            true => relabel(line, letters),
+            false => line.clone(),
+        })
+        .collect()
+}
+
+fn relabel(s: &str, letters: &HashMap<char, char>) -> String {
+    s.chars().map(|c| *letters.get(&c).unwrap_or(&c)).collect()
+}
+
+fn solve(lines: &[String]) -> (String, String) {
+    let (stacks, moves) = parse(lines).unwrap_or_else(|err| err.exit());
+    let tops = part_1(&stacks, &moves).and_then(|first| Ok((first, part_2(&stacks, &moves)?)));
+    tops.unwrap_or_else(|err| err.exit())
+}
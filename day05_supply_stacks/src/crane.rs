@@ -0,0 +1,99 @@
+use crate::Stacks;
+
+// Moves `count` crates from the top of stack `from` onto stack `to`. Moves
+// are checked against the stacks before a crane is asked to make them.
+pub trait Crane {
+    fn apply(&self, stacks: &mut Stacks, count: usize, from: usize, to: usize);
+}
+
+// one crate at a time, so a moved block ends up upside down
+pub struct CrateMover9000;
+
+// the whole block at once, keeping its order
+pub struct CrateMover9001;
+
+// blocks of up to `capacity` crates at a time, topmost block first
+pub struct Limited {
+    pub capacity: usize,
+}
+
+// the whole block at once, slid under the crates already on the target stack
+pub struct BottomInsert;
+
+impl Crane for CrateMover9000 {
+    fn apply(&self, stacks: &mut Stacks, count: usize, from: usize, to: usize) {
+        Limited { capacity: 1 }.apply(stacks, count, from, to);
+    }
+}
+
+impl Crane for CrateMover9001 {
+    fn apply(&self, stacks: &mut Stacks, count: usize, from: usize, to: usize) {
+        let block = stacks.take(from, count);
+        stacks.0[to].extend(block);
+    }
+}
+
+impl Crane for Limited {
+    fn apply(&self, stacks: &mut Stacks, count: usize, from: usize, to: usize) {
+        let mut left = count;
+        while left > 0 {
+            let lift = left.min(self.capacity.max(1));
+            CrateMover9001.apply(stacks, lift, from, to);
+            left -= lift;
+        }
+    }
+}
+
+impl Crane for BottomInsert {
+    fn apply(&self, stacks: &mut Stacks, count: usize, from: usize, to: usize) {
+        let block = stacks.take(from, count);
+        stacks.0[to].splice(0..0, block);
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, clap::ValueEnum)]
+pub enum Model {
+    #[value(name = "9000")]
+    CrateMover9000,
+    #[value(name = "9001")]
+    CrateMover9001,
+    Limited,
+    Bottom,
+}
+
+impl Model {
+    // `capacity` is only used by the limited crane
+    pub fn crane(self, capacity: usize) -> Box<dyn Crane> {
+        match self {
+            Model::CrateMover9000 => Box::new(CrateMover9000),
+            Model::CrateMover9001 => Box::new(CrateMover9001),
+            Model::Limited => Box::new(Limited { capacity }),
+            Model::Bottom => Box::new(BottomInsert),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stacks() -> Stacks {
+        let stack = |labels: &str| labels.chars().map(String::from).collect();
+        Stacks(vec![stack("ABCDE"), stack("X")])
+    }
+
+    #[test]
+    fn cranes_stack_blocks_differently() {
+        let moved = |crane: &dyn Crane| {
+            let mut stacks = stacks();
+            crane.apply(&mut stacks, 4, 0, 1);
+            stacks.0[1].concat()
+        };
+
+        assert_eq!(moved(&CrateMover9000), "XEDCB");
+        assert_eq!(moved(&CrateMover9001), "XBCDE");
+        assert_eq!(moved(&Limited { capacity: 3 }), "XCDEB");
+        assert_eq!(moved(&Limited { capacity: 1 }), moved(&CrateMover9000));
+        assert_eq!(moved(&BottomInsert), "BCDEX");
+    }
+}
@@ -0,0 +1,429 @@
+pub mod anonymise;
+pub mod crane;
+
+use common::{Answer, ParseError};
+use crane::{Crane, CrateMover9000, CrateMover9001};
+use std::fmt::Display;
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let (stacks, moves) = parse(lines)?;
+
+    Ok((
+        part_1(&stacks, &moves)?.into(),
+        part_2(&stacks, &moves)?.into(),
+    ))
+}
+
+pub fn part_1(stacks: &Stacks, moves: &[(usize, usize, usize)]) -> Result<String, ParseError> {
+    Ok(stacks.run(moves, &CrateMover9000)?.tops())
+}
+
+pub fn part_2(stacks: &Stacks, moves: &[(usize, usize, usize)]) -> Result<String, ParseError> {
+    Ok(stacks.run(moves, &CrateMover9001)?.tops())
+}
+
+// The labels of the crates of every stack from the bottom up, stacks in the
+// order of the drawing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stacks(pub Vec<Vec<String>>);
+
+impl Stacks {
+    // a copy with every move made by `crane`
+    pub fn run(
+        &self,
+        moves: &[(usize, usize, usize)],
+        crane: &dyn Crane,
+    ) -> Result<Stacks, ParseError> {
+        self.run_with(moves, crane, |_, _| {})
+    }
+
+    // Like `run`, calling `step` with the index of every move once it is
+    // made. Stops at the first move taking more crates than its stack has.
+    pub fn run_with(
+        &self,
+        moves: &[(usize, usize, usize)],
+        crane: &dyn Crane,
+        mut step: impl FnMut(usize, &Stacks),
+    ) -> Result<Stacks, ParseError> {
+        let mut stacks = self.clone();
+        for (i, &(count, from, to)) in moves.iter().enumerate() {
+            let width = stacks.0.len();
+            let Some(available) = stacks.0.get(from).map(Vec::len).filter(|_| to < width) else {
+                let missing = from.max(to) + 1;
+                return Err(ParseError::new(format!(
+                    "move {}: no stack {missing}",
+                    i + 1
+                )));
+            };
+            if available < count {
+                return Err(ParseError::new(format!(
+                    "move {}: {count} crates requested from stack {}, which has {available}",
+                    i + 1,
+                    from + 1
+                )));
+            }
+            crane.apply(&mut stacks, count, from, to);
+            step(i, &stacks);
+        }
+        Ok(stacks)
+    }
+
+    // the `count` crates at the top of stack `from`, bottom one first
+    pub fn take(&mut self, from: usize, count: usize) -> Vec<String> {
+        let at = self.0[from].len() - count;
+        self.0[from].split_off(at)
+    }
+
+    // the labels at the top of every stack, a space for empty stacks
+    pub fn tops(&self) -> String {
+        self.0
+            .iter()
+            .map(|stack| stack.last().map_or(" ", String::as_str))
+            .collect()
+    }
+
+    // The stacks drawn the way the input draws them, cells as wide as the
+    // widest label and the numbers under their left edge.
+    pub fn drawing(&self) -> Vec<String> {
+        let height = self.0.iter().map(Vec::len).max().unwrap_or(0);
+        let width = self
+            .0
+            .iter()
+            .flatten()
+            .map(|label| label.chars().count() + 2);
+        let width = width.max().unwrap_or(3);
+        let mut lines = Vec::new();
+
+        for row in (0..height).rev() {
+            let cells: Vec<String> = self
+                .0
+                .iter()
+                .map(|stack| match stack.get(row) {
+                    Some(label) => format!("{:<width$}", format!("[{label}]")),
+                    None => " ".repeat(width),
+                })
+                .collect();
+            lines.push(cells.join(" "));
+        }
+
+        let footer: Vec<String> = (1..=self.0.len())
+            .map(|i| format!(" {i:<0$}", width - 1))
+            .collect();
+        lines.push(footer.join(" "));
+
+        lines
+    }
+}
+
+type Input = (Stacks, Vec<(usize, usize, usize)>);
+
+// The drawing runs down to the first line of nothing but numbers, which
+// numbers the stacks, and the moves start after the blank line under it.
+pub fn parse(lines: &[String]) -> Result<Input, ParseError> {
+    let footer = lines
+        .iter()
+        .position(|line| is_footer(line))
+        .ok_or_else(|| ParseError::new("no stack numbers under the drawing"))?;
+
+    let stacks = parse_stacks(&lines[..footer], &lines[footer])?;
+
+    if lines
+        .get(footer + 1)
+        .is_some_and(|line| !line.trim().is_empty())
+    {
+        return Err(ParseError::at(
+            footer + 1,
+            "expected a blank line under the stack numbers",
+        ));
+    }
+
+    let moves_start = footer + 2;
+    let move_lines = lines.get(moves_start..).unwrap_or_default();
+    let moves = parse_moves(move_lines, stacks.0.len()).map_err(|err| err.offset(moves_start))?;
+
+    Ok((stacks, moves))
+}
+fn parse_moves(
+    move_lines: &[String],
+    stack_count: usize,
+) -> Result<Vec<(usize, usize, usize)>, ParseError> {
+    move_lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let move_info = match tokens.as_slice() {
+                ["move", count, "from", from, "to", to] => {
+                    [count, from, to].map(|nstr| nstr.parse::<usize>().ok())
+                }
+                _ => [None; 3],
+            };
+
+            match move_info {
+                [Some(count), Some(from @ 1..), Some(to @ 1..)]
+                    if from <= stack_count && to <= stack_count =>
+                {
+                    Ok((count, from - 1, to - 1))
+                }
+                _ => Err(ParseError::at(i, format!("invalid move: {line:?}"))),
+            }
+        })
+        .collect()
+}
+
+fn is_footer(line: &str) -> bool {
+    let mut tokens = line.split_whitespace().peekable();
+    tokens.peek().is_some() && tokens.all(|token| token.bytes().all(|b| b.is_ascii_digit()))
+}
+
+// Crates belong to the stack whose number is under them, so lines can be
+// trimmed and labels can be wider than a letter. Columns count characters.
+fn parse_stacks(drawing: &[String], footer: &str) -> Result<Stacks, ParseError> {
+    let numbers = spans(footer);
+    for (k, &(first, _, number)) in numbers.iter().enumerate() {
+        if number.parse::<usize>() != Ok(k + 1) {
+            return Err(at(
+                drawing.len(),
+                first,
+                format!("expected stack number {}, found {number:?}", k + 1),
+            ));
+        }
+    }
+
+    let mut stacks: Vec<Vec<String>> = vec![vec![]; numbers.len()];
+
+    for (row, (i, line)) in drawing.iter().enumerate().rev().enumerate() {
+        for (first, last, label) in parse_crates(i, line)? {
+            let under: Vec<usize> = (0..numbers.len())
+                .filter(|&k| numbers[k].0 <= last && first <= numbers[k].1)
+                .collect();
+            let stack = match under.as_slice() {
+                [stack] => *stack,
+                [] => {
+                    return Err(at(
+                        i,
+                        first,
+                        format!("crate [{label}] is not above a stack number"),
+                    ))
+                }
+                [a, b, ..] => {
+                    return Err(at(
+                        i,
+                        first,
+                        format!("crate [{label}] spans stacks {} and {}", a + 1, b + 1),
+                    ))
+                }
+            };
+
+            match stacks[stack].len() {
+                height if height > row => {
+                    return Err(at(
+                        i,
+                        first,
+                        format!("second crate above stack {} on the line", stack + 1),
+                    ))
+                }
+                height if height < row => {
+                    return Err(at(
+                        i,
+                        first,
+                        format!("crate [{label}] floats above stack {}", stack + 1),
+                    ))
+                }
+                _ => stacks[stack].push(label),
+            }
+        }
+    }
+
+    Ok(Stacks(stacks))
+}
+
+// the runs of non-blank characters with their first and last columns
+fn spans(line: &str) -> Vec<(usize, usize, &str)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    let columns = line.char_indices().map(Some).chain([None]);
+
+    for (column, c) in columns.enumerate() {
+        match (c, start) {
+            (Some((byte, c)), None) if !c.is_whitespace() => start = Some((column, byte)),
+            (Some((_, c)), Some(_)) if !c.is_whitespace() => {}
+            (c, Some((first, from))) => {
+                let to = c.map_or(line.len(), |(byte, _)| byte);
+                spans.push((first, column - 1, &line[from..to]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    spans
+}
+
+// the crates on line `i` of the drawing with the columns of their brackets
+fn parse_crates(i: usize, line: &str) -> Result<Vec<(usize, usize, String)>, ParseError> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut crates = Vec::new();
+    let mut column = 0;
+
+    while column < chars.len() {
+        match chars[column] {
+            c if c.is_whitespace() => column += 1,
+            '[' => {
+                let label = &chars[column + 1..];
+                let len = label
+                    .iter()
+                    .position(|&c| c == ']' || c == '[' || c.is_whitespace());
+                match len.map(|len| (len, label[len])) {
+                    Some((0, ']')) => return Err(at(i, column, "empty crate")),
+                    Some((len, ']')) => {
+                        crates.push((column, column + len + 1, label[..len].iter().collect()));
+                        column += len + 2;
+                    }
+                    _ => return Err(at(i, column, "unclosed crate")),
+                }
+            }
+            c => return Err(at(i, column, format!("unexpected {c:?}"))),
+        }
+    }
+
+    Ok(crates)
+}
+
+// an error at the 0-based `column` of line `i`
+fn at(i: usize, column: usize, message: impl Display) -> ParseError {
+    ParseError::at(i, format!("column {}: {message}", column + 1))
+}
+
+pub fn print_stacks(stacks: &Stacks) {
+    for stack in &stacks.0 {
+        for c in stack {
+            print!("{c} ");
+        }
+        println!();
+    }
+}
+
+// every move followed by the stacks once it is made
+pub fn print_trace(
+    stacks: &Stacks,
+    moves: &[(usize, usize, usize)],
+    crane: &dyn Crane,
+) -> Result<Stacks, ParseError> {
+    for line in stacks.drawing() {
+        println!("{}", line.trim_end());
+    }
+
+    stacks.run_with(moves, crane, |i, stacks| {
+        let (count, from, to) = moves[i];
+        println!();
+        println!(
+            "after move {}, {count} from {} to {}",
+            i + 1,
+            from + 1,
+            to + 1
+        );
+        for line in stacks.drawing() {
+            println!("{}", line.trim_end());
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    // trailing spaces cut when `trimmed`
+    fn draw(stacks: &[Vec<String>], moves: &[(usize, usize, usize)], trimmed: bool) -> String {
+        let mut lines = Stacks(stacks.to_vec()).drawing();
+        if trimmed {
+            for line in &mut lines {
+                line.truncate(line.trim_end().len());
+            }
+        }
+        lines.push(String::new());
+
+        for (count, from, to) in moves {
+            lines.push(format!("move {count} from {} to {}", from + 1, to + 1));
+        }
+
+        lines.join("\n")
+    }
+
+    fn input() -> impl Strategy<Value = Input> {
+        (1..10usize).prop_flat_map(|width| {
+            let stacks = prop::collection::vec(prop::collection::vec("[A-Z]{1,3}", 1..8), width);
+            let moves = prop::collection::vec((0..10usize, 0..width, 0..width), 0..20);
+            (stacks.prop_map(Stacks), moves)
+        })
+    }
+
+    #[test]
+    fn reads_trimmed_lines_and_long_labels() {
+        let text = "    [DD]\n[N] [C]\n[Z] [MM] [P]\n 1   2   3\n\nmove 1 from 2 to 1";
+        let (stacks, moves) = parse(&split_lines(text)).unwrap();
+
+        assert_eq!(stacks.tops(), "NDDP");
+        assert_eq!(stacks.0[1], ["MM", "C", "DD"]);
+        assert_eq!(moves, [(1, 1, 0)]);
+    }
+
+    #[test]
+    fn moves_are_checked_against_the_stacks() {
+        let (stacks, _) = parse(&split_lines("[A] [B]\n 1   2")).unwrap();
+
+        assert_eq!(
+            stacks.run(&[(1, 0, 1), (2, 0, 1)], &CrateMover9000),
+            Err(ParseError::new(
+                "move 2: 2 crates requested from stack 1, which has 0"
+            ))
+        );
+        assert_eq!(
+            stacks.run(&[(1, 0, 2)], &CrateMover9001),
+            Err(ParseError::new("move 1: no stack 3"))
+        );
+        assert_eq!(stacks.run(&[(0, 0, 1)], &CrateMover9001), Ok(stacks));
+    }
+
+    #[test]
+    fn malformed_drawings_point_at_the_problem() {
+        let error = |text: &str| parse(&split_lines(text)).unwrap_err();
+
+        assert_eq!(
+            error("[A] [B\n 1   2"),
+            ParseError::at(0, "column 5: unclosed crate")
+        );
+        assert_eq!(
+            error("    [A]\n[B]\n 1   2"),
+            ParseError::at(0, "column 5: crate [A] floats above stack 2")
+        );
+        assert_eq!(
+            error("[A]      [B]\n 1   2"),
+            ParseError::at(0, "column 10: crate [B] is not above a stack number")
+        );
+        assert_eq!(
+            error("[A] [B]\n 1   3"),
+            ParseError::at(1, "column 6: expected stack number 2, found \"3\"")
+        );
+        assert_eq!(
+            error("[A]\n 1\nmove 1 from 1 to 1"),
+            ParseError::at(2, "expected a blank line under the stack numbers")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips((stacks, moves) in input(), trimmed: bool) {
+            let text = draw(&stacks.0, &moves, trimmed);
+
+            prop_assert_eq!(parse(&split_lines(&text)), Ok((stacks, moves)));
+        }
+
+        #[test]
+        fn parse_never_panics(text in "([\\[\\] A-Z0-9\n]|move|from|to)*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+    }
+}
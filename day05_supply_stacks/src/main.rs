@@ -1,99 +1,62 @@
-use std::io::{stdin, BufRead};
-
-fn main() {
-    let lines: Vec<String> = stdin().lock().lines().filter_map(Result::ok).collect();
-
-    let stack_lines: Vec<String> = lines
-        .iter()
-        .take_while(|line| line.contains("["))
-        .cloned()
-        .collect();
-
-    let stacks = parse_stacks(&stack_lines);
-    let moves = parse_moves(&lines[stack_lines.len() + 2..]);
-
-    print_stacks(&stacks);
-
-    println!("part 1: {}", part_1(&stacks, &moves));
-    println!("part 2: {}", part_2(&stacks, &moves));
-}
-
-fn part_1(stacks: &Vec<Vec<char>>, moves: &Vec<(usize, usize, usize)>) -> String {
-    let mut stacks = stacks.clone();
-
-    for &(count, from, to) in moves {
-        for _ in 0..count {
-            let el = stacks[from].pop().expect("invalid stack");
-            stacks[to].push(el);
-        }
-    }
-
-    read_stacks(&stacks)
+use clap::Parser;
+use common::{print_part, read_lines};
+use day05_supply_stacks::{anonymise, crane, parse, print_stacks, print_trace, solve};
+
+#[derive(Parser)]
+struct Args {
+    /// Print an anonymised copy of the input instead of solving it
+    #[arg(long)]
+    anonymise: bool,
+
+    /// Seed for the anonymisation, picked at random when missing
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Rearrange the stacks with this crane and print the top crates instead of solving
+    #[arg(long, value_enum)]
+    crane: Option<crane::Model>,
+
+    /// Crates the limited crane lifts at once
+    #[arg(long, default_value_t = 3)]
+    capacity: usize,
+
+    /// Print the stacks after every move, made by the crane picked with --crane (9000 by default)
+    #[arg(long)]
+    trace: bool,
 }
 
-fn part_2(stacks: &Vec<Vec<char>>, moves: &Vec<(usize, usize, usize)>) -> String {
-    let mut stacks = stacks.clone();
+fn main() {
+    let args = Args::parse();
+    let lines = read_lines();
 
-    for &(count, from, to) in moves {
-        let at = stacks[from].len() - count;
-        let mut tail = stacks[from].split_off(at);
-        stacks[to].append(&mut tail);
+    if args.anonymise {
+        anonymise::run(&lines, args.seed);
+        return;
     }
 
-    read_stacks(&stacks)
-}
-
-fn parse_moves(move_lines: &[String]) -> Vec<(usize, usize, usize)> {
-    let moves: Vec<(usize, usize, usize)> = move_lines
-        .iter()
-        .map(|line| {
-            let move_info: Vec<usize> = line
-                .replace("move", "")
-                .replace("from", "")
-                .replace("to", "")
-                .split_whitespace()
-                .map(|nstr| nstr.parse::<usize>())
-                .filter_map(Result::ok)
-                .collect();
-
-            (move_info[0], move_info[1] - 1, move_info[2] - 1)
-        })
-        .collect();
-    moves
-}
-
-fn parse_stacks(lines: &Vec<String>) -> Vec<Vec<char>> {
-    let width = (lines.last().expect("invalid line").len() + 1) / 4;
-    let mut stacks: Vec<Vec<char>> = vec![vec![]; width];
+    let (stacks, moves) = parse(&lines).unwrap_or_else(|err| err.exit());
 
-    for line in lines.iter().rev() {
-        for i in 0..width {
-            let char = line.as_bytes()[i * 4 + 1];
-            if char != b' ' {
-                stacks[i].push(char as char);
-            }
-        }
+    if args.trace {
+        let model = args.crane.unwrap_or(crane::Model::CrateMover9000);
+        let crane = model.crane(args.capacity);
+        let stacks = print_trace(&stacks, &moves, crane.as_ref()).unwrap_or_else(|err| err.exit());
+        println!("tops: {}", stacks.tops());
+        return;
     }
 
-    stacks
-}
-
-fn read_stacks(stacks: &Vec<Vec<char>>) -> String {
-    let mut s = String::new();
+    print_stacks(&stacks);
 
-    for stack in stacks {
-        let el = stack.last().expect("invalid stack");
-        s.push(*el);
+    if let Some(model) = args.crane {
+        let crane = model.crane(args.capacity);
+        let stacks = stacks
+            .run(&moves, crane.as_ref())
+            .unwrap_or_else(|err| err.exit());
+        println!("tops: {}", stacks.tops());
+        return;
     }
 
-    s
-}
+    let (part_1, part_2) = solve(&lines).unwrap_or_else(|err| err.exit());
 
-fn print_stacks(stacks: &Vec<Vec<char>>) {
-    for stack in stacks {
-        for c in stack {
-            print!("{c} ");
-        }
-        println!();
-    }
+    print_part(1, &part_1);
+    print_part(2, &part_2);
 }
@@ -0,0 +1,67 @@
+use common::{Answer, ParseError};
+use std::collections::HashSet;
+
+// one marker per input line
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let streams = parse(lines)?;
+
+    let part_1 = find_markers(&streams, 4)?;
+    let part_2 = find_markers(&streams, 14)?;
+
+    Ok((Answer::per_line(part_1), Answer::per_line(part_2)))
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<String>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(
+            |(i, line)| match line.bytes().all(|c| c.is_ascii_lowercase()) {
+                true => Ok(line.clone()),
+                false => Err(ParseError::at(i, format!("invalid datastream: {line:?}"))),
+            },
+        )
+        .collect()
+}
+
+fn find_markers(streams: &[String], length: usize) -> Result<Vec<usize>, ParseError> {
+    streams
+        .iter()
+        .enumerate()
+        .map(|(i, stream)| {
+            find_marker(stream, length).ok_or_else(|| ParseError::at(i, "no marker found"))
+        })
+        .collect()
+}
+
+fn find_marker(seq: &str, length: usize) -> Option<usize> {
+    for (i, marker) in seq.as_bytes().windows(length).enumerate() {
+        let set: HashSet<u8> = marker.iter().cloned().collect();
+        if set.len() == length {
+            return Some(i + length);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(streams in prop::collection::vec("[a-z]{1,100}", 0..10)) {
+            let text = streams.join("\n");
+
+            prop_assert_eq!(parse(&split_lines(&text)), Ok(streams));
+        }
+
+        #[test]
+        fn solve_never_panics(text in "[a-z\n]*|\\PC*") {
+            let _ = solve(&split_lines(&text));
+        }
+    }
+}
@@ -1,27 +1,11 @@
-use std::collections::HashSet;
-use std::io::{stdin, BufRead};
+use common::{print_part, read_lines};
+use day06_tuning_trouble::solve;
 
 fn main() {
-    let lines: Vec<String> = stdin().lock().lines().filter_map(Result::ok).collect();
+    let lines = read_lines();
 
-    println!("part 1:");
-    for line in lines.iter() {
-        println!("{}", find_marker(&line, 4));
-    }
+    let (part_1, part_2) = solve(&lines).unwrap_or_else(|err| err.exit());
 
-    println!("part 1:");
-    for line in lines.iter() {
-        println!("{}", find_marker(&line, 14));
-    }
-}
-
-fn find_marker(seq: &String, length: usize) -> usize {
-    for (i, marker) in seq.as_bytes().windows(length).enumerate() {
-        let set: HashSet<u8> = marker.iter().cloned().collect();
-        if set.len() == length {
-            return i + length;
-        }
-    }
-
-    panic!("no marker found");
+    print_part(1, &part_1);
+    print_part(2, &part_2);
 }
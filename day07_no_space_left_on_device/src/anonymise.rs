@@ -0,0 +1,100 @@
+use std::{collections::HashMap, process};
+
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::solve;
+
+// Every file and directory name is replaced through one shared mapping, so a
+// `cd` still finds the directory listed under the same name, and the entries
+// of each `ls` listing are shuffled. Sizes are kept as they are.
+pub fn run(lines: &[String], seed: Option<u64>) {
+    let seed = seed.unwrap_or_else(rand::random);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let anonymised = anonymise(lines, &mut rng);
+
+    let expected = solve(lines).unwrap_or_else(|err| err.exit());
+    let actual = solve(&anonymised).unwrap_or_else(|err| err.exit());
+
+    if expected != actual {
+        eprintln!("anonymisation changed the answers: expected {expected:?}, got {actual:?}");
+        process::exit(1);
+    }
+
+    for line in anonymised {
+        println!("{line}");
+    }
+    eprintln!("seed {seed}: answers preserved");
+}
+
+fn anonymise(lines: &[String], rng: &mut StdRng) -> Vec<String> {
+    let names = rename_all(lines, rng);
+    let rename = |name: &str| names.get(name).cloned().unwrap_or(name.to_string());
+
+    let mut result = Vec::new();
+    let mut listing = Vec::new();
+
+    for line in lines {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let renamed = match tokens.as_slice() {
+            ["$", "cd", dir] => format!("$ cd {}", rename(dir)),
+            ["dir", dir] => format!("dir {}", rename(dir)),
+            [size, file] if *size != "$" => format!("{size} {}", rename(file)),
+            _ => line.clone(),
+        };
+
+        if tokens.first() == Some(&"$") {
+            listing.shuffle(rng);
+            result.append(&mut listing);
+            result.push(renamed);
+        } else {
+            listing.push(renamed);
+        }
+    }
+
+    listing.shuffle(rng);
+    result.append(&mut listing);
+
+    result
+}
+
+fn rename_all(lines: &[String], rng: &mut StdRng) -> HashMap<String, String> {
+    let mut names: Vec<&str> = Vec::new();
+
+    for line in lines {
+        let name = match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["$", "cd", "/" | ".."] => continue,
+            ["$", "cd", name] => *name,
+            ["$", _] => continue,
+            [_, name] => *name,
+            _ => continue,
+        };
+
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    names.shuffle(rng);
+
+    names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| (name.to_string(), generated_name(i)))
+        .collect()
+}
+
+// 0 -> "a", 25 -> "z", 26 -> "aa", ...
+fn generated_name(mut i: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'a' + (i % 26) as u8);
+        if i < 26 {
+            break;
+        }
+        i = i / 26 - 1;
+    }
+    name.reverse();
+
+    String::from_utf8(name).unwrap()
+}
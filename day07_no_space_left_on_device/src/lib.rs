@@ -0,0 +1,276 @@
+pub mod anonymise;
+
+use common::{Answer, ParseError};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+struct Directory {
+    name: String,
+    directories: HashMap<String, DirRef>,
+    files: HashMap<String, u32>,
+    parent: Option<DirRef>,
+    // of everything under the directory, so it can exceed a file's u32
+    size: u64,
+}
+
+pub struct FileSystem {
+    root: DirRef,
+    current: DirRef,
+}
+
+type DirRef = Rc<RefCell<Directory>>;
+
+impl Directory {
+    fn new(name: String) -> DirRef {
+        Rc::new(RefCell::new(Directory {
+            name,
+            directories: HashMap::new(),
+            files: HashMap::new(),
+            parent: None,
+            size: 0,
+        }))
+    }
+
+    fn tree(&self, offset: usize) {
+        println!("{}- {} (dir)", " ".repeat(offset), self.name);
+        for child in self.directories.values() {
+            child.borrow().tree(offset + 2);
+        }
+        for (name, size) in &self.files {
+            println!("{}- {name} (file, {size})", " ".repeat(offset + 2))
+        }
+    }
+
+    fn compute_size(&mut self) -> u64 {
+        let mut dir_size: u64 = 0;
+        for child in self.directories.values() {
+            dir_size += child.borrow_mut().compute_size();
+        }
+        for (_, size) in &self.files {
+            dir_size += *size as u64;
+        }
+
+        self.size = dir_size;
+        dir_size
+    }
+}
+
+impl FileSystem {
+    pub fn build(lines: &[String]) -> Result<Self, ParseError> {
+        let root = Directory::new("/".to_string());
+        let mut fs = Self {
+            root: Rc::clone(&root),
+            current: Rc::clone(&root),
+        };
+
+        if lines.first().map(String::as_str) != Some("$ cd /") {
+            return Err(ParseError::at(0, "expected `$ cd /`"));
+        }
+
+        for (i, line) in lines.iter().enumerate().skip(1) {
+            let cmd: Vec<&str> = line.split_whitespace().collect();
+            match cmd.as_slice() {
+                ["$", "cd", dir] => {
+                    fs.cd(dir)
+                        .ok_or_else(|| ParseError::at(i, "cannot leave the root directory"))?;
+                }
+                ["$", "ls"] => {}
+                ["dir", _] => {}
+                [size, file] => {
+                    let size: u32 = size
+                        .parse()
+                        .map_err(|_| ParseError::at(i, format!("invalid file size: {size:?}")))?;
+                    fs.mkfile(file, size);
+                }
+                c => {
+                    return Err(ParseError::at(i, format!("unrecognized command: {:?}", c)));
+                }
+            }
+        }
+
+        Ok(fs)
+    }
+
+    fn cd(&mut self, dir: &str) -> Option<()> {
+        let new_dir = {
+            if dir == "/" {
+                Rc::clone(&self.root)
+            } else if dir == ".." {
+                let current = self.current.borrow();
+                current.parent.as_ref()?.clone()
+            } else {
+                let exists = {
+                    let current = self.current.borrow();
+                    current.directories.get(dir).is_some()
+                };
+                if !exists {
+                    self.mkdir(dir);
+                }
+
+                Rc::clone(self.current.borrow().directories.get(dir).unwrap())
+            }
+        };
+
+        self.current = new_dir;
+        Some(())
+    }
+
+    fn mkdir(&self, dir: &str) {
+        if !self.current.borrow().directories.contains_key(dir) {
+            let new_dir = Directory::new(dir.to_string());
+            new_dir.borrow_mut().parent = Some(Rc::clone(&self.current));
+            self.current
+                .borrow_mut()
+                .directories
+                .insert(dir.to_string(), new_dir);
+        }
+    }
+
+    fn mkfile(&self, file: &str, size: u32) {
+        self.current
+            .borrow_mut()
+            .files
+            .insert(file.to_string(), size);
+    }
+
+    fn compute_sizes(&self) -> u64 {
+        self.root.borrow_mut().compute_size()
+    }
+
+    fn get_dirs(&self) -> Vec<DirRef> {
+        let mut stack: Vec<DirRef> = Vec::new();
+        let mut result: Vec<DirRef> = Vec::new();
+
+        stack.push(self.root.clone());
+
+        while stack.len() != 0 {
+            let top = { stack.pop().unwrap().clone() };
+            for child in top.borrow().directories.values() {
+                stack.push(child.clone());
+            }
+            result.push(top);
+        }
+
+        result
+    }
+
+    pub fn tree(&self) {
+        self.root.borrow().tree(0);
+    }
+}
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let fs = parse(lines)?;
+    let total_size = fs.compute_sizes();
+
+    Ok((part_1(&fs).into(), part_2(&fs, total_size)?.into()))
+}
+
+pub fn parse(lines: &[String]) -> Result<FileSystem, ParseError> {
+    FileSystem::build(lines)
+}
+
+fn part_1(fs: &FileSystem) -> u64 {
+    let size_threshold = 100_000;
+    fs.get_dirs()
+        .iter()
+        .filter(|d| d.borrow().size <= size_threshold)
+        .fold(0, |acc, d| acc + d.borrow().size)
+}
+
+const DISK_SIZE: u64 = 70_000_000;
+const SPACE_NEEDED: u64 = 30_000_000;
+
+fn part_2(fs: &FileSystem, total_size: u64) -> Result<u64, ParseError> {
+    let free = DISK_SIZE.checked_sub(total_size).ok_or_else(|| {
+        ParseError::new(format!(
+            "the files take {total_size}, more than the disk's {DISK_SIZE}"
+        ))
+    })?;
+    let space_to_free = SPACE_NEEDED.saturating_sub(free);
+
+    // the root takes up `total_size`, which is always enough
+    Ok(fs
+        .get_dirs()
+        .iter()
+        .map(|d| d.borrow().size)
+        .filter(|&size| size >= space_to_free)
+        .min()
+        .expect("the root frees enough space"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+    use std::collections::BTreeMap;
+
+    type Files = BTreeMap<Vec<String>, u32>;
+
+    fn files(dir: &DirRef, path: &mut Vec<String>, result: &mut Files) {
+        let dir = dir.borrow();
+        for (name, size) in &dir.files {
+            path.push(name.clone());
+            result.insert(path.clone(), *size);
+            path.pop();
+        }
+        for (name, child) in &dir.directories {
+            path.push(name.clone());
+            files(child, path, result);
+            path.pop();
+        }
+    }
+
+    // visits every file from the root, listing it and climbing back up
+    fn terminal_output(files: &Files) -> String {
+        let mut lines = vec!["$ cd /".to_string()];
+
+        for (path, size) in files {
+            let (file, dirs) = path.split_last().unwrap();
+            for dir in dirs {
+                lines.push(format!("$ cd {dir}"));
+            }
+            lines.push("$ ls".to_string());
+            lines.push(format!("{size} {file}"));
+            for _ in dirs {
+                lines.push("$ cd ..".to_string());
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    #[test]
+    fn files_larger_than_the_disk_are_an_error() {
+        let text = "$ cd /\n$ ls\n80000000 a";
+
+        assert_eq!(
+            solve(&split_lines(text)),
+            Err(ParseError::new(
+                "the files take 80000000, more than the disk's 70000000"
+            ))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(expected in prop::collection::btree_map(prop::collection::vec("[a-e]", 1..5), any::<u32>(), 0..20)) {
+            let fs = parse(&split_lines(&terminal_output(&expected))).unwrap();
+
+            let mut actual = Files::new();
+            files(&fs.root, &mut vec![], &mut actual);
+
+            prop_assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "(\\$ cd /\n)?([$ a-e0-9\n.]|cd|ls|dir)*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+
+        #[test]
+        fn solve_never_panics(text in "\\$ cd /\n((\\$ cd [a-c.]{1,2}|\\$ ls|dir [a-c]|[0-9]{1,10} [a-c])\n)*") {
+            let _ = solve(&split_lines(&text));
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use common::{Answer, ParseError};
+use std::{
+    fmt::{Debug, Display},
+    ops::{Index, IndexMut},
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Grid<T> {
+    v: Vec<T>,
+    w: usize,
+    h: usize,
+}
+
+impl Grid<u8> {
+    fn parse(lines: &[String]) -> Result<Grid<u8>, ParseError> {
+        let mut v: Vec<u8> = Vec::new();
+        let w = lines.first().map_or(0, String::len);
+        let h = lines.len();
+
+        if w == 0 {
+            return Err(ParseError::new("empty grid"));
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            if line.len() != w {
+                return Err(ParseError::at(
+                    i,
+                    format!("expected {w} trees, got {}", line.len()),
+                ));
+            }
+            if let Some(c) = line.chars().find(|c| !c.is_ascii_digit()) {
+                return Err(ParseError::at(i, format!("invalid tree height: {c:?}")));
+            }
+
+            v.extend(line.bytes().map(|b| b - b'0'));
+        }
+
+        Ok(Grid { v, w, h })
+    }
+}
+
+impl<T: Default + Clone + Display + Debug> Grid<T> {
+    fn new(w: usize, h: usize) -> Grid<T> {
+        Grid {
+            v: vec![T::default(); w * h],
+            w,
+            h,
+        }
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        for i in 0..self.h {
+            for j in 0..self.w {
+                print!("{:?} ", self[(i, j)]);
+            }
+            println!();
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.v[i * self.w + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.v[i * self.w + j]
+    }
+}
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let grid = parse(lines)?;
+
+    Ok((
+        compute_visible(&grid).into(),
+        compute_scenic_score(&grid).into(),
+    ))
+}
+
+pub fn parse(lines: &[String]) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(lines)
+}
+
+fn compute_scenic_score(grid: &Grid<u8>) -> usize {
+    let mut up: Grid<usize> = Grid::new(grid.w, grid.h);
+    let mut down: Grid<usize> = Grid::new(grid.w, grid.h);
+    let mut left: Grid<usize> = Grid::new(grid.w, grid.h);
+    let mut right: Grid<usize> = Grid::new(grid.w, grid.h);
+
+    // viewing distance up-down
+    for j in 0..grid.w {
+        let mut last_stop = vec![0usize; 10];
+        for i in 0..grid.h {
+            let stop = last_stop[grid[(i, j)] as usize..].iter().max().unwrap();
+            up[(i, j)] = *stop;
+            last_stop[grid[(i, j)] as usize] = i;
+        }
+
+        last_stop = vec![grid.h - 1; 10];
+        for i in (0..grid.h).rev() {
+            let stop = last_stop[grid[(i, j)] as usize..].iter().min().unwrap();
+            down[(i, j)] = *stop;
+            last_stop[grid[(i, j)] as usize] = i;
+        }
+    }
+
+    // viewing distance left-right
+    for i in 0..grid.h {
+        let mut last_stop = vec![0usize; 10];
+
+        for j in 0..grid.w {
+            let stop = last_stop[grid[(i, j)] as usize..].iter().max().unwrap();
+            left[(i, j)] = *stop;
+            last_stop[grid[(i, j)] as usize] = j;
+        }
+
+        last_stop = vec![grid.w - 1; 10];
+        for j in (0..grid.w).rev() {
+            let stop = last_stop[grid[(i, j)] as usize..].iter().min().unwrap();
+            right[(i, j)] = *stop;
+            last_stop[grid[(i, j)] as usize] = j;
+        }
+    }
+
+    let mut max = 0usize;
+    for i in 0..grid.h {
+        for j in 0..grid.w {
+            let scene_up = i - up[(i, j)];
+            let scene_down = down[(i, j)] - i;
+            let scene_left = j - left[(i, j)];
+            let scene_right = right[(i, j)] - j;
+
+            let scenic_score = scene_up * scene_down * scene_left * scene_right;
+
+            if scenic_score > max {
+                max = scenic_score;
+            }
+        }
+    }
+
+    max
+}
+
+fn compute_visible(grid: &Grid<u8>) -> usize {
+    let w = grid.w;
+    let h = grid.h;
+
+    let mut visible: Grid<bool> = Grid::new(grid.w, grid.h);
+
+    // edges
+    for i in 0..grid.h {
+        visible[(i, 0)] = true;
+        visible[(i, w - 1)] = true;
+    }
+    for j in 0..w {
+        visible[(0, j)] = true;
+        visible[(h - 1, j)] = true;
+    }
+
+    // along rows
+    for i in 1..h - 1 {
+        let mut max = 0;
+
+        for j in 0..w {
+            if grid[(i, j)] > max {
+                visible[(i, j)] = true;
+                max = grid[(i, j)];
+            }
+        }
+        max = 0;
+        for j in (0..w).rev() {
+            if grid[(i, j)] > max {
+                visible[(i, j)] = true;
+                max = grid[(i, j)];
+            }
+        }
+    }
+
+    // along columns
+
+    for j in 1..w - 1 {
+        let mut max = 0u8;
+
+        for i in 0..h {
+            if grid[(i, j)] > max {
+                visible[(i, j)] = true;
+                max = grid[(i, j)];
+            }
+        }
+
+        max = 0;
+        for i in (0..h).rev() {
+            if grid[(i, j)] > max {
+                visible[(i, j)] = true;
+                max = grid[(i, j)];
+            }
+        }
+    }
+
+    visible.v.iter().filter(|b| **b).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    fn grid() -> impl Strategy<Value = Grid<u8>> {
+        (1..10usize, 1..10usize).prop_flat_map(|(w, h)| {
+            prop::collection::vec(0..10u8, w * h).prop_map(move |v| Grid { v, w, h })
+        })
+    }
+
+    fn draw(grid: &Grid<u8>) -> String {
+        grid.v
+            .chunks(grid.w)
+            .map(|row| row.iter().map(|d| (b'0' + d) as char).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(expected in grid()) {
+            prop_assert_eq!(parse(&split_lines(&draw(&expected))).unwrap(), expected);
+        }
+
+        #[test]
+        fn solve_never_panics(text in "[0-9\n]*|\\PC*") {
+            let _ = solve(&split_lines(&text));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use common::{Answer, ParseError};
+use geometry::{Direction, Point};
+use std::collections::HashSet;
+
+struct Rope {
+    v: Vec<Point>,
+}
+
+impl Rope {
+    fn new(size: usize) -> Self {
+        Rope {
+            v: vec![Point::ORIGIN; size],
+        }
+    }
+    fn step(&mut self, dir: &Direction) {
+        self.v[0] += *dir;
+
+        for i in 0..self.v.len() - 1 {
+            let (left, right) = self.v.split_at_mut(i + 1);
+            let h = &mut left[i];
+            let t = &mut right[0];
+
+            Rope::step_segment(h, t);
+        }
+    }
+
+    fn step_segment(h: &mut Point, t: &mut Point) {
+        if h.chebyshev(t) > 1 {
+            *t += (*h - *t).signum();
+        }
+    }
+
+    fn tail(&self) -> &Point {
+        self.v.last().unwrap()
+    }
+
+    #[allow(dead_code)]
+    fn head(&self) -> &Point {
+        self.v.first().unwrap()
+    }
+}
+
+fn parse_move(s: &str) -> Option<(Direction, u32)> {
+    let (dir, steps) = s.split_once(' ')?;
+    let dir = match dir {
+        "U" => Direction::U,
+        "R" => Direction::R,
+        "D" => Direction::D,
+        "L" => Direction::L,
+        _ => return None,
+    };
+
+    Some((dir, steps.parse().ok()?))
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<(Direction, u32)>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            parse_move(line).ok_or_else(|| ParseError::at(i, format!("invalid move: {line:?}")))
+        })
+        .collect()
+}
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let moves = parse(lines)?;
+
+    let mut rope = Rope::new(2);
+    let part_1 = move_rope(&mut rope, &moves);
+
+    let mut rope = Rope::new(10);
+    let part_2 = move_rope(&mut rope, &moves);
+
+    Ok((part_1.into(), part_2.into()))
+}
+
+fn move_rope(rope: &mut Rope, moves: &[(Direction, u32)]) -> usize {
+    let mut tail_positions = HashSet::<Point>::new();
+
+    tail_positions.insert(*rope.tail());
+
+    for (dir, steps) in moves {
+        for _ in 0..*steps {
+            rope.step(dir);
+            tail_positions.insert(*rope.tail());
+        }
+    }
+
+    tail_positions.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    fn letter(dir: Direction) -> char {
+        match dir {
+            Direction::U => 'U',
+            Direction::R => 'R',
+            Direction::D => 'D',
+            Direction::L => 'L',
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(expected in prop::collection::vec((prop::sample::select(Direction::ALL.to_vec()), any::<u32>()), 0..50)) {
+            let text: Vec<String> = expected
+                .iter()
+                .map(|(dir, steps)| format!("{} {steps}", letter(*dir)))
+                .collect();
+
+            prop_assert_eq!(parse(&split_lines(&text.join("\n"))).unwrap(), expected);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "([URDL] [0-9]+\n)*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+    }
+}
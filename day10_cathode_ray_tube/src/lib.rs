@@ -0,0 +1,155 @@
+use common::{Answer, ParseError};
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    fn parse(s: &str) -> Option<Self> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        match tokens.as_slice() {
+            ["noop"] => Some(Instruction::Noop),
+            ["addx", val] => Some(Instruction::Addx(val.parse().ok()?)),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<Instruction>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            Instruction::parse(line)
+                .ok_or_else(|| ParseError::at(i, format!("unrecognized command: {line:?}")))
+        })
+        .collect()
+}
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let instructions = parse(lines)?;
+    let signal_strenghts = compute_signal_strenghts(&instructions);
+    let screen = render_screen(&instructions);
+
+    Ok((signal_strenghts.into(), screen_lines(&screen).into()))
+}
+
+fn compute_signal_strenghts(instructions: &[Instruction]) -> i64 {
+    let mut signal_strenghts = HashMap::<u32, i64>::new();
+
+    let mut reg_x = 1i64;
+    let mut cycles = 0u32;
+
+    let checkpoints = [20, 60, 100, 140, 180, 220];
+    let mut c_i = 0;
+
+    for instr in instructions {
+        let mut added_value = 0;
+        match instr {
+            Instruction::Noop => {
+                cycles += 1;
+            }
+            Instruction::Addx(val) => {
+                cycles += 2;
+                added_value = *val as i64;
+            }
+        }
+
+        if c_i < checkpoints.len() && cycles >= checkpoints[c_i] {
+            signal_strenghts.insert(checkpoints[c_i], reg_x);
+            c_i += 1;
+        }
+        reg_x += added_value;
+    }
+
+    signal_strenghts
+        .iter()
+        .fold(0, |acc, (k, v)| acc + *k as i64 * v)
+}
+
+fn render_screen(instructions: &[Instruction]) -> Vec<bool> {
+    let mut reg_x: isize = 1;
+    let mut cycle: isize = 0;
+
+    let mut screen = vec![false; 40 * 6];
+
+    render_pixel(&mut screen, reg_x, cycle);
+
+    for instr in instructions {
+        match instr {
+            Instruction::Noop => {
+                cycle += 1;
+            }
+            Instruction::Addx(val) => {
+                render_pixel(&mut screen, reg_x, cycle + 1);
+                cycle += 2;
+                reg_x += *val as isize;
+            }
+        }
+        render_pixel(&mut screen, reg_x, cycle);
+    }
+
+    screen
+}
+
+// cycles past the last row are not drawn
+fn render_pixel(screen: &mut [bool], reg_x: isize, cycle: isize) {
+    let col = cycle % 40;
+    if let Some(pixel) = screen.get_mut(cycle as usize) {
+        if (reg_x - col).abs() <= 1 {
+            *pixel = true;
+        }
+    }
+}
+
+fn screen_lines(screen: &[bool]) -> Vec<String> {
+    screen
+        .chunks(40)
+        .map(|row| {
+            row.iter()
+                .map(|pixel| match pixel {
+                    false => "  ",
+                    true => "██",
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            Just(Instruction::Noop),
+            any::<i32>().prop_map(Instruction::Addx),
+        ]
+    }
+
+    fn format(instr: &Instruction) -> String {
+        match instr {
+            Instruction::Noop => "noop".to_string(),
+            Instruction::Addx(val) => format!("addx {val}"),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(expected in prop::collection::vec(instruction(), 0..300)) {
+            let text: Vec<String> = expected.iter().map(format).collect();
+
+            prop_assert_eq!(parse(&split_lines(&text.join("\n"))).unwrap(), expected);
+        }
+
+        #[test]
+        fn solve_never_panics(text in "((noop|addx -?[0-9]+)\n){0,300}|\\PC*") {
+            let _ = solve(&split_lines(&text));
+        }
+    }
+}
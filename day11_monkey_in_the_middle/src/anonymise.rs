@@ -0,0 +1,67 @@
+use std::{collections::HashMap, process};
+
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::{parse_label, solve};
+
+// The input is solved first, so it is known to be well formed here.
+//
+// Monkeys keep their place in the input (and so their turn order) but get new
+// numbers, with throw targets rewritten to match. Starting items are shuffled
+// within each monkey, which does not change how often each monkey inspects.
+pub fn run(lines: &[String], seed: Option<u64>) {
+    let seed = seed.unwrap_or_else(rand::random);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let expected = solve(lines).unwrap_or_else(|err| err.exit());
+
+    let anonymised = anonymise(lines, &mut rng);
+    let actual = solve(&anonymised).unwrap_or_else(|err| err.exit());
+
+    if expected != actual {
+        eprintln!("anonymisation changed the answers: expected {expected:?}, got {actual:?}");
+        process::exit(1);
+    }
+
+    for line in anonymised {
+        println!("{line}");
+    }
+    eprintln!("seed {seed}: answers preserved");
+}
+
+fn anonymise(lines: &[String], rng: &mut StdRng) -> Vec<String> {
+    let labels: Vec<usize> = lines
+        .chunks(7)
+        .map(|chunk| parse_label(&chunk[0]).unwrap())
+        .collect();
+    let mut shuffled = labels.clone();
+    shuffled.shuffle(rng);
+    let relabel: HashMap<usize, usize> = labels.into_iter().zip(shuffled).collect();
+
+    let mut result = Vec::new();
+
+    for chunk in lines.chunks(7) {
+        for (i, line) in chunk.iter().enumerate() {
+            let line = match i {
+                0 => format!("Monkey {}:", relabel[&parse_label(line).unwrap()]),
+                1 => shuffle_items(line, rng),
+                4 | 5 => {
+                    let (prefix, target) = line.rsplit_once(' ').unwrap();
+                    format!("{prefix} {}", relabel[&target.parse().unwrap()])
+                }
+                _ => line.clone(),
+            };
+            result.push(line);
+        }
+    }
+
+    result
+}
+
+fn shuffle_items(line: &str, rng: &mut StdRng) -> String {
+    let (prefix, items) = line.split_once(": ").unwrap();
+    let mut items: Vec<&str> = items.split(", ").collect();
+    items.shuffle(rng);
+
+    format!("{prefix}: {}", items.join(", "))
+}
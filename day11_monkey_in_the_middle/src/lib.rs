@@ -0,0 +1,509 @@
+#![allow(dead_code)]
+pub mod anonymise;
+
+use common::{Answer, ParseError};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    num::ParseIntError,
+    ops::{Add, Div, Mul, Rem},
+    str::FromStr,
+};
+
+const MAX_DIV: usize = 23;
+
+#[derive(Debug, PartialEq)]
+pub struct Monkey<T> {
+    items: Vec<T>,
+    inspect_count: u64,
+    op: Operation,
+    test: u32,
+    throw: (usize, usize),
+    relief: u32,
+}
+
+#[derive(Debug, PartialEq)]
+enum Operand {
+    Mul,
+    Add,
+    Square,
+}
+
+#[derive(Debug, PartialEq)]
+struct Operation {
+    op: Operand,
+    value: u32,
+}
+
+// Worry levels the monkeys can track. The operations give `None` when the
+// result does not fit.
+pub trait Worry: Sized {
+    fn plus(&self, value: u32) -> Option<Self>;
+    fn times(&self, value: u32) -> Option<Self>;
+    fn squared(&self) -> Option<Self>;
+    fn relieved(self, relief: u32) -> Self;
+    fn divisible_by(&self, divisor: u32) -> bool;
+}
+
+impl Worry for u64 {
+    fn plus(&self, value: u32) -> Option<u64> {
+        self.checked_add(value.into())
+    }
+
+    fn times(&self, value: u32) -> Option<u64> {
+        self.checked_mul(value.into())
+    }
+
+    fn squared(&self) -> Option<u64> {
+        self.checked_mul(*self)
+    }
+
+    fn relieved(self, relief: u32) -> u64 {
+        self / u64::from(relief)
+    }
+
+    fn divisible_by(&self, divisor: u32) -> bool {
+        self.is_multiple_of(u64::from(divisor))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NumAsMods {
+    mods: Vec<u32>,
+}
+
+impl NumAsMods {
+    fn new() -> NumAsMods {
+        NumAsMods {
+            mods: vec![0; MAX_DIV + 1],
+        }
+    }
+
+    fn from(number: u64) -> NumAsMods {
+        let mut result = NumAsMods::new();
+        for i in 1..result.mods.len() {
+            result.mods[i] = (number % i as u64) as u32;
+        }
+
+        result
+    }
+}
+
+impl Add<u32> for &NumAsMods {
+    type Output = NumAsMods;
+    fn add(self, other: u32) -> NumAsMods {
+        let mut result = NumAsMods::new();
+
+        for i in 1..self.mods.len() {
+            result.mods[i] = (self.mods[i] + other % i as u32) % i as u32;
+        }
+
+        result
+    }
+}
+
+impl Mul<u32> for &NumAsMods {
+    type Output = NumAsMods;
+    fn mul(self, other: u32) -> NumAsMods {
+        let mut result = NumAsMods::new();
+
+        for i in 1..self.mods.len() {
+            result.mods[i] = (self.mods[i] * (other % i as u32)) % i as u32;
+        }
+
+        result
+    }
+}
+
+impl Mul for &NumAsMods {
+    type Output = NumAsMods;
+    fn mul(self, other: Self) -> NumAsMods {
+        let mut result = NumAsMods::new();
+
+        for i in 1..self.mods.len() {
+            result.mods[i] = (self.mods[i] * other.mods[i]) % i as u32;
+        }
+
+        result
+    }
+}
+
+// residues stay below `MAX_DIV`, so these never overflow
+impl Worry for NumAsMods {
+    fn plus(&self, value: u32) -> Option<NumAsMods> {
+        Some(self + value)
+    }
+
+    fn times(&self, value: u32) -> Option<NumAsMods> {
+        Some(self * value)
+    }
+
+    fn squared(&self) -> Option<NumAsMods> {
+        Some(self * self)
+    }
+
+    fn relieved(self, relief: u32) -> NumAsMods {
+        self / relief
+    }
+
+    fn divisible_by(&self, divisor: u32) -> bool {
+        self % divisor == 0
+    }
+}
+
+impl Rem<u32> for &NumAsMods {
+    type Output = u32;
+
+    fn rem(self, other: u32) -> u32 {
+        self.mods[other as usize]
+    }
+}
+
+// dummy
+impl Div<u32> for NumAsMods {
+    type Output = NumAsMods;
+    fn div(self, _: u32) -> NumAsMods {
+        let mut result = NumAsMods::new();
+        for i in 0..=MAX_DIV {
+            result.mods[i] = self.mods[i]
+        }
+        result
+    }
+}
+
+impl FromStr for NumAsMods {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let num = s.parse::<u64>()?;
+        Ok(NumAsMods::from(num))
+    }
+}
+
+impl<T: FromStr + Debug + Clone> Monkey<T>
+where
+    T::Err: Debug,
+    T: Worry,
+{
+    // `lines` holds the six lines after the "Monkey N:" header
+    fn parse(lines: &[String], relief: u32) -> Result<Monkey<T>, ParseError> {
+        let field = |i: usize, prefix: &str| {
+            lines
+                .get(i)
+                .and_then(|line| line.trim().strip_prefix(prefix))
+                .ok_or_else(|| ParseError::at(i, format!("expected `{prefix}`")))
+        };
+        let number = |i: usize, prefix: &str| {
+            field(i, prefix)?
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| ParseError::at(i, "invalid number"))
+        };
+
+        let items = field(0, "Starting items:")?.trim();
+        let items: Vec<T> = match items.is_empty() {
+            true => Vec::new(),
+            false => items
+                .split(", ")
+                .map(|tok| tok.parse().map_err(|_| ParseError::at(0, "invalid item")))
+                .collect::<Result<_, _>>()?,
+        };
+
+        let op = Operation::parse(field(1, "Operation: new = old ")?)
+            .ok_or_else(|| ParseError::at(1, "unknown operation"))?;
+
+        let test = number(2, "Test: divisible by")?;
+        if !(1..=MAX_DIV as u32).contains(&test) {
+            return Err(ParseError::at(2, format!("divisor must be 1 to {MAX_DIV}")));
+        }
+
+        let if_true = number(3, "If true: throw to monkey")? as usize;
+        let if_false = number(4, "If false: throw to monkey")? as usize;
+
+        Ok(Monkey {
+            items,
+            inspect_count: 0,
+            op,
+            test,
+            throw: (if_true, if_false),
+            relief,
+        })
+    }
+
+    // `None` when a worry level overflows
+    fn execute_throw<'s>(&'s mut self) -> Option<HashMap<usize, Vec<T>>> {
+        let mut thrown_items: HashMap<usize, Vec<T>> = HashMap::new();
+
+        for value in self.items.iter() {
+            let value: T = self.op.apply(value)?.relieved(self.relief);
+
+            let throw_to = if self.test(&value) {
+                self.throw.0
+            } else {
+                self.throw.1
+            };
+
+            self.inspect_count += 1;
+
+            let destination_items = thrown_items.entry(throw_to).or_insert(Vec::new());
+            destination_items.push(value);
+        }
+
+        self.items.clear();
+        Some(thrown_items)
+    }
+
+    fn print(&self) {
+        println!("starting items: {:?}", self.items);
+        println!("operation: {:?}", self.op);
+        println!("test: divisible by {}", self.test);
+        println!("throws: {:?}", self.throw);
+    }
+
+    fn test(&self, value: &T) -> bool {
+        value.divisible_by(self.test)
+    }
+}
+
+impl Operation {
+    fn parse(raw: &str) -> Option<Self> {
+        let tokens: Vec<&str> = raw.split_whitespace().collect();
+
+        match tokens.as_slice() {
+            ["*", "old"] => Some(Self {
+                value: 0,
+                op: Operand::Square,
+            }),
+            ["+", val] => Some(Self {
+                value: val.parse().ok()?,
+                op: Operand::Add,
+            }),
+            ["*", val] => Some(Self {
+                value: val.parse().ok()?,
+                op: Operand::Mul,
+            }),
+            _ => None,
+        }
+    }
+
+    fn apply<T: Worry>(&self, value: &T) -> Option<T> {
+        match self.op {
+            Operand::Add => value.plus(self.value),
+            Operand::Mul => value.times(self.value),
+            Operand::Square => value.squared(),
+        }
+    }
+}
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let mut monkeys = parse(lines)?;
+    let part_1 = compute_monkey_business(&mut monkeys, 20)?;
+
+    let mut monkeys = parse_monkeys::<NumAsMods>(lines, 1)?;
+    let part_2 = compute_monkey_business(&mut monkeys, 10000)?;
+
+    Ok((part_1.into(), part_2.into()))
+}
+
+// the monkeys as they start part 1
+pub fn parse(lines: &[String]) -> Result<Vec<Monkey<u64>>, ParseError> {
+    parse_monkeys(lines, 3)
+}
+
+pub fn print_inspections<T>(monkeys: &[Monkey<T>]) {
+    for (i, monkey) in monkeys.iter().enumerate() {
+        println!("monkey {i} inspected {} items", monkey.inspect_count);
+    }
+}
+
+// Monkeys take their turns in the order they appear in the input, while throw
+// targets refer to the number in the "Monkey N:" header.
+pub fn parse_monkeys<T>(lines: &[String], relief: u32) -> Result<Vec<Monkey<T>>, ParseError>
+where
+    T: FromStr + Debug + Clone,
+    T::Err: Debug,
+    T: Worry,
+{
+    let mut monkeys = Vec::<Monkey<T>>::new();
+    let mut positions = HashMap::<usize, usize>::new();
+    for (i, chunk) in lines.chunks(7).enumerate() {
+        let start = i * 7;
+        let label =
+            parse_label(&chunk[0]).ok_or_else(|| ParseError::at(start, "expected `Monkey N:`"))?;
+        if positions.insert(label, i).is_some() {
+            return Err(ParseError::at(start, format!("duplicate monkey {label}")));
+        }
+        if chunk.get(6).is_some_and(|line| !line.is_empty()) {
+            return Err(ParseError::at(start + 6, "expected a blank line"));
+        }
+        monkeys.push(Monkey::parse(&chunk[1..], relief).map_err(|err| err.offset(start + 1))?);
+    }
+
+    if monkeys.len() < 2 {
+        return Err(ParseError::new("expected at least two monkeys"));
+    }
+
+    for (i, monkey) in monkeys.iter_mut().enumerate() {
+        let position = |label| {
+            positions
+                .get(&label)
+                .copied()
+                .ok_or_else(|| ParseError::at(i * 7, format!("throws to missing monkey {label}")))
+        };
+        monkey.throw = (position(monkey.throw.0)?, position(monkey.throw.1)?);
+    }
+
+    Ok(monkeys)
+}
+
+fn parse_label(line: &str) -> Option<usize> {
+    line.strip_prefix("Monkey ")?
+        .strip_suffix(':')?
+        .parse()
+        .ok()
+}
+
+// Errors point at the header of the monkey whose worry level overflowed.
+pub fn compute_monkey_business<T>(monkeys: &mut [Monkey<T>], rounds: u32) -> Result<u64, ParseError>
+where
+    T: FromStr + Debug + Clone,
+    T::Err: Debug,
+    T: Worry,
+{
+    for round in 1..=rounds {
+        for m_id in 0..monkeys.len() {
+            let mut thrown_items = monkeys[m_id].execute_throw().ok_or_else(|| {
+                ParseError::at(m_id * 7, format!("worry level overflows in round {round}"))
+            })?;
+            for (id, items) in thrown_items.iter_mut() {
+                monkeys[*id].items.append(&mut items.clone());
+            }
+        }
+    }
+
+    let mut inspect_counts: Vec<u64> = monkeys.iter().map(|m| m.inspect_count).collect();
+    inspect_counts.sort_by(|c1, c2| c2.cmp(c1));
+
+    inspect_counts[0]
+        .checked_mul(inspect_counts[1])
+        .ok_or_else(|| ParseError::new("the monkey business overflows"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            Just(()).prop_map(|_| Operation {
+                op: Operand::Square,
+                value: 0
+            }),
+            any::<u32>().prop_map(|value| Operation {
+                op: Operand::Add,
+                value
+            }),
+            any::<u32>().prop_map(|value| Operation {
+                op: Operand::Mul,
+                value
+            }),
+        ]
+    }
+
+    fn monkeys() -> impl Strategy<Value = Vec<Monkey<u64>>> {
+        (2..8usize).prop_flat_map(|n| {
+            let monkey = (
+                prop::collection::vec(any::<u64>(), 0..10),
+                operation(),
+                1..=MAX_DIV as u32,
+                (0..n, 0..n),
+            )
+                .prop_map(|(items, op, test, throw)| Monkey {
+                    items,
+                    inspect_count: 0,
+                    op,
+                    test,
+                    throw,
+                    relief: 3,
+                });
+            prop::collection::vec(monkey, n)
+        })
+    }
+
+    fn format(monkeys: &[Monkey<u64>]) -> String {
+        let blocks: Vec<String> = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, monkey)| {
+                let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+                let op = match monkey.op.op {
+                    Operand::Square => "* old".to_string(),
+                    Operand::Add => format!("+ {}", monkey.op.value),
+                    Operand::Mul => format!("* {}", monkey.op.value),
+                };
+                [
+                    format!("Monkey {i}:"),
+                    format!("  Starting items: {}", items.join(", ")),
+                    format!("  Operation: new = old {op}"),
+                    format!("  Test: divisible by {}", monkey.test),
+                    format!("    If true: throw to monkey {}", monkey.throw.0),
+                    format!("    If false: throw to monkey {}", monkey.throw.1),
+                ]
+                .join("\n")
+            })
+            .collect();
+
+        blocks.join("\n\n")
+    }
+
+    // monkey 0 squares its item, then monkey 1 keeps it and wears it down
+    const SQUARED_ONCE: &str = "Monkey 0:
+  Starting items: 70000
+  Operation: new = old * old
+  Test: divisible by 1
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items:
+  Operation: new = old + 1
+  Test: divisible by 1
+    If true: throw to monkey 1
+    If false: throw to monkey 1";
+
+    #[test]
+    fn squares_past_u32() {
+        assert_eq!(
+            solve(&split_lines(SQUARED_ONCE)),
+            Ok((20.into(), 10000.into()))
+        );
+    }
+
+    #[test]
+    fn worry_levels_past_u64_are_an_error() {
+        let text = SQUARED_ONCE.replacen("monkey 1", "monkey 0", 2);
+        assert_eq!(
+            solve(&split_lines(&text)),
+            Err(ParseError::at(0, "worry level overflows in round 3"))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(expected in monkeys()) {
+            prop_assert_eq!(parse(&split_lines(&format(&expected))).unwrap(), expected);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "((Monkey [0-9]:|  Starting items: [0-9, ]*|  Operation: new = old [*+] (old|[0-9]+)|  Test: divisible by [0-9]+|    If (true|false): throw to monkey [0-9]|)\n){0,30}|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+
+        #[test]
+        fn solve_never_panics(text in "((Monkey [0-9]:|  Starting items: [0-9, ]*|  Operation: new = old [*+] (old|[0-9]+)|  Test: divisible by [0-9]+|    If (true|false): throw to monkey [0-9]|)\n){0,30}") {
+            let _ = solve(&split_lines(&text));
+        }
+    }
+}
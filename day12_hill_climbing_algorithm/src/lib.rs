@@ -0,0 +1,147 @@
+use common::{Answer, ParseError};
+use geometry::{Point, Rect};
+use grid::Grid;
+use std::collections::LinkedList;
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let (hm, start, end) = parse(lines)?;
+
+    let distances = compute_distances(&hm, end);
+
+    let part_1 = distances[start];
+
+    let mut min_dist = u32::MAX;
+
+    for ind in 0..distances.size {
+        if hm.v[ind] == b'a' && distances.v[ind] < min_dist {
+            min_dist = distances.v[ind];
+        }
+    }
+
+    Ok((part_1.into(), min_dist.into()))
+}
+
+// the height map with the start and end marked at their heights
+pub fn parse(lines: &[String]) -> Result<(Grid<u8>, Point, Point), ParseError> {
+    let mut hm = Grid::parse(lines)?;
+
+    if let Some(i) =
+        hm.v.iter()
+            .position(|c| !matches!(c, b'a'..=b'z' | b'S' | b'E'))
+    {
+        return Err(ParseError::at(
+            i / hm.w,
+            format!("invalid height: {:?}", hm.v[i] as char),
+        ));
+    }
+    for mark in [b'S', b'E'] {
+        if hm.v.iter().filter(|&&c| c == mark).count() != 1 {
+            return Err(ParseError::new(format!(
+                "expected exactly one {:?}",
+                mark as char
+            )));
+        }
+    }
+
+    let start = hm.find(b'S').unwrap();
+    let end = hm.find(b'E').unwrap();
+
+    hm[start] = b'a';
+    hm[end] = b'z';
+
+    Ok((hm, start, end))
+}
+
+fn compute_distances(hm: &Grid<u8>, start: Point) -> Grid<u32> {
+    let mut queue = LinkedList::<Point>::new();
+    let mut distances = Grid::<u32>::new(hm.w, hm.h);
+    distances.init(u32::MAX);
+
+    queue.push_back(start);
+    distances[start] = 0;
+
+    while !queue.is_empty() {
+        let cell = queue.pop_front().unwrap();
+        let dist = distances[cell];
+
+        let neighbors = get_neighbors(hm, cell);
+
+        for neighbor in neighbors.iter() {
+            if dist + 1 < distances[*neighbor] {
+                distances[*neighbor] = dist + 1;
+                queue.push_back(*neighbor);
+            }
+        }
+    }
+
+    distances
+}
+
+fn get_neighbors(hm: &Grid<u8>, cell: Point) -> Vec<Point> {
+    let mut neighbors = Vec::new();
+    let bounds = Rect::from_size(hm.w, hm.h).expect("the map holds at least S and E");
+
+    for n_cell in cell.neighbors() {
+        if !bounds.contains(&n_cell) {
+            continue;
+        }
+
+        if hm[n_cell] + 1 < hm[cell] {
+            continue;
+        }
+
+        neighbors.push(n_cell);
+    }
+
+    neighbors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    // heights, width and the distinct start and end indices
+    fn height_map() -> impl Strategy<Value = (Vec<u8>, usize, usize, usize)> {
+        (1..10usize, 1..10usize)
+            .prop_filter("room for S and E", |(w, h)| w * h >= 2)
+            .prop_flat_map(|(w, h)| {
+                let n = w * h;
+                (
+                    prop::collection::vec(b'a'..=b'z', n),
+                    Just(w),
+                    prop::sample::subsequence((0..n).collect::<Vec<_>>(), 2).prop_shuffle(),
+                )
+            })
+            .prop_map(|(v, w, ends)| (v, w, ends[0], ends[1]))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips((mut v, w, start, end) in height_map()) {
+            v[start] = b'a';
+            v[end] = b'z';
+
+            let mut drawn = v.clone();
+            drawn[start] = b'S';
+            drawn[end] = b'E';
+            let text: Vec<String> = drawn
+                .chunks(w)
+                .map(|row| String::from_utf8(row.to_vec()).unwrap())
+                .collect();
+
+            let (hm, s, e) = parse(&split_lines(&text.join("\n"))).unwrap();
+            let point = |i: usize| Point::new((i % w) as isize, (i / w) as isize);
+
+            prop_assert_eq!(hm.v, v);
+            prop_assert_eq!(hm.w, w);
+            prop_assert_eq!((s, e), (point(start), point(end)));
+        }
+
+        #[test]
+        fn solve_never_panics(text in "[a-zSE\n]*|\\PC*") {
+            let _ = solve(&split_lines(&text));
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use common::{Answer, ParseError};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+// deeper packets are rejected rather than risking the stack
+const MAX_DEPTH: usize = 256;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Element {
+    Num(u32),
+    List(Vec<Element>),
+}
+
+impl Element {
+    fn parse(s: &str) -> Option<Element> {
+        let s = s.as_bytes();
+        if s.first() != Some(&b'[') {
+            return None;
+        }
+
+        match Element::parse_list(s, 1, 1)? {
+            (list, end) if end == s.len() => Some(list),
+            _ => None,
+        }
+    }
+
+    fn parse_num(s: &[u8], i: usize) -> Option<(Element, usize)> {
+        let mut i = i;
+        let mut n = 0u32;
+
+        while i < s.len() && s[i].is_ascii_digit() {
+            n = n.checked_mul(10)?.checked_add((s[i] - b'0') as u32)?;
+            i += 1;
+        }
+
+        Some((Element::Num(n), i))
+    }
+
+    fn parse_list(s: &[u8], i: usize, depth: usize) -> Option<(Element, usize)> {
+        let mut i = i;
+        let mut list = Vec::<Element>::new();
+
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        while i < s.len() {
+            match s[i] {
+                b',' => i += 1,
+                b'[' => {
+                    let (list_el, j) = Self::parse_list(s, i + 1, depth + 1)?;
+                    list.push(list_el);
+                    i = j;
+                }
+                b']' => return Some((Element::List(list), i + 1)),
+                b'0'..=b'9' => {
+                    let (num_el, j) = Self::parse_num(s, i)?;
+                    list.push(num_el);
+                    i = j;
+                }
+                _ => return None,
+            }
+        }
+
+        None
+    }
+
+    fn order(left: &Element, right: &Element) -> Ordering {
+        match (left, right) {
+            (Self::Num(l), Self::Num(r)) => l.cmp(r),
+            (Self::List(_), Self::Num(_)) => Self::order(left, &Self::List(vec![right.clone()])),
+            (Self::Num(_), Self::List(_)) => Self::order(&Self::List(vec![left.clone()]), right),
+            (Self::List(l), Self::List(r)) => {
+                let mut i = 0;
+
+                while i < l.len() && i < r.len() {
+                    match Self::order(&l[i], &r[i]) {
+                        Ordering::Equal => i += 1,
+                        ord => return ord,
+                    }
+                }
+
+                l.len().cmp(&r.len())
+            }
+        }
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Element::Num(n) => write!(f, "{n}"),
+            Element::List(list) => {
+                write!(f, "[")?;
+                for (i, el) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{el}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Element::order(self, other)
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Element::order(self, other))
+    }
+}
+
+pub fn parse(lines: &[String]) -> Result<Vec<(Element, Element)>, ParseError> {
+    let packet = |i: usize| {
+        let line = lines
+            .get(i)
+            .ok_or_else(|| ParseError::at(i, "expected a packet"))?;
+        Element::parse(line).ok_or_else(|| ParseError::at(i, format!("invalid packet: {line:?}")))
+    };
+
+    let mut pairs = Vec::new();
+
+    for start in (0..lines.len()).step_by(3) {
+        if lines.get(start + 2).is_some_and(|line| !line.is_empty()) {
+            return Err(ParseError::at(start + 2, "expected a blank line"));
+        }
+
+        pairs.push((packet(start)?, packet(start + 1)?));
+    }
+
+    Ok(pairs)
+}
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let mut result = 0;
+
+    let mut packets = Vec::<Element>::new();
+
+    for (i, (left, right)) in parse(lines)?.into_iter().enumerate() {
+        match left < right {
+            true => result += i + 1,
+            _ => {}
+        }
+
+        packets.push(left);
+        packets.push(right);
+    }
+
+    let divider_1 = Element::parse("[[2]]").unwrap();
+    let divider_2 = Element::parse("[[6]]").unwrap();
+
+    packets.push(divider_1.clone());
+    packets.push(divider_2.clone());
+
+    packets.sort();
+
+    let i1 = packets.iter().position(|p| *p == divider_1).unwrap() + 1;
+    let i2 = packets.iter().position(|p| *p == divider_2).unwrap() + 1;
+
+    Ok((result.into(), (i1 * i2).into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    fn element() -> impl Strategy<Value = Element> {
+        let leaf = any::<u32>().prop_map(Element::Num);
+        let tree = leaf.prop_recursive(4, 32, 5, |inner| {
+            prop::collection::vec(inner, 0..5).prop_map(Element::List)
+        });
+
+        prop::collection::vec(tree, 0..5).prop_map(Element::List)
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(expected in prop::collection::vec((element(), element()), 0..10)) {
+            let text: Vec<String> = expected
+                .iter()
+                .map(|(left, right)| format!("{left}\n{right}"))
+                .collect();
+
+            prop_assert_eq!(parse(&split_lines(&text.join("\n\n"))).unwrap(), expected);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[\\[\\],0-9\n]*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+
+        #[test]
+        fn parse_rejects_deep_nesting(depth in MAX_DEPTH + 1..10 * MAX_DEPTH) {
+            let packet = "[".repeat(depth) + &"]".repeat(depth);
+
+            prop_assert!(parse(&[packet.clone(), packet]).is_err());
+        }
+    }
+}
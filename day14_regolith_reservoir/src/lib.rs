@@ -0,0 +1,198 @@
+use common::{Answer, ParseError};
+use geometry::{Direction8, Point, Rect};
+use grid::Grid;
+
+pub type Trace = Vec<Point>;
+pub type Cave = Grid<u8>;
+
+const BG: &str = "\x1b[90m░░\x1b[0m";
+const ROCK: &str = "\x1b[37m██\x1b[0m";
+const SAND: &str = "\x1b[33m▒▒\x1b[0m";
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let (traces, max_y) = parse(lines)?;
+
+    let (mut cave, spawn_cell) = make_cave(&traces, max_y);
+
+    let sand_to_floor = fill_until_overflow(&mut cave, spawn_cell);
+    let total_sand = sand_to_floor + fill_until_blocked(&mut cave, spawn_cell);
+
+    Ok((sand_to_floor.into(), total_sand.into()))
+}
+
+// sand comes to rest until a grain would fall past the lowest rock
+pub fn fill_until_overflow(cave: &mut Cave, spawn_cell: Point) -> u32 {
+    let mut total_sand = 0;
+
+    'outer: loop {
+        let mut sand = spawn_cell;
+        loop {
+            if sand.y as usize >= cave.h - 2 {
+                break 'outer;
+            } else if cave[sand + Direction8::D] == 0 {
+                sand += Direction8::D;
+            } else if cave[sand + Direction8::DL] == 0 {
+                sand += Direction8::DL;
+            } else if cave[sand + Direction8::DR] == 0 {
+                sand += Direction8::DR;
+            } else {
+                cave[sand] = 2;
+                total_sand += 1;
+                break;
+            }
+        }
+    }
+
+    total_sand
+}
+
+// sand piles up on the floor until the spawn cell is covered
+pub fn fill_until_blocked(cave: &mut Cave, spawn_cell: Point) -> u32 {
+    let mut total_sand = 0;
+
+    loop {
+        let mut sand = spawn_cell;
+        if cave[sand] == 2 {
+            break;
+        }
+        loop {
+            if cave[sand + Direction8::D] == 0 {
+                sand += Direction8::D;
+            } else if cave[sand + Direction8::DL] == 0 {
+                sand += Direction8::DL;
+            } else if cave[sand + Direction8::DR] == 0 {
+                sand += Direction8::DR;
+            } else {
+                cave[sand] = 2;
+                total_sand += 1;
+                break;
+            }
+        }
+    }
+
+    total_sand
+}
+
+// the rock traces and the depth of the lowest rock
+pub fn parse(lines: &[String]) -> Result<(Vec<Trace>, usize), ParseError> {
+    let mut traces: Vec<Trace> = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let mut trace: Trace = Vec::new();
+        for xy in line.split(" -> ") {
+            let point = parse_point(xy)
+                .ok_or_else(|| ParseError::at(i, format!("invalid point: {xy:?}")))?;
+            if trace
+                .last()
+                .is_some_and(|p: &Point| p.x != point.x && p.y != point.y)
+            {
+                return Err(ParseError::at(i, "rock segments must be straight lines"));
+            }
+            trace.push(point);
+        }
+        traces.push(trace);
+    }
+
+    let bounds = Rect::bounding(traces.iter().flatten().cloned())
+        .ok_or_else(|| ParseError::new("no traces"))?;
+
+    Ok((traces, bounds.max.y as usize))
+}
+
+fn parse_point(xy: &str) -> Option<Point> {
+    let (x, y) = xy.split_once(',')?;
+    let x: u32 = x.parse().ok()?;
+    let y: u32 = y.parse().ok()?;
+
+    Some(Point::new(x as isize, y as isize))
+}
+
+pub fn make_cave(traces: &[Trace], max_y: usize) -> (Cave, Point) {
+    let mut cave = Grid::<u8>::new(2 * max_y + 5, max_y + 3);
+    let offset = Point::new(cave.w as isize / 2 - 500, 0);
+
+    // rock further out than the floor reaches can never be touched by sand
+    let bounds = Rect::from_size(cave.w, cave.h).expect("the cave is never empty");
+    for trace in traces {
+        for slice in trace.windows(2) {
+            let segment = Rect::new(slice[0] + offset, slice[1] + offset);
+            for p in segment.intersection(&bounds).iter().flat_map(Rect::points) {
+                cave[p] = 1;
+            }
+        }
+    }
+
+    let floor_i = cave.h - 1;
+    for j in 0..cave.w {
+        cave[(floor_i, j)] = 1;
+    }
+
+    let spawn_cell = Point::new(500, 0) + offset;
+
+    cave[spawn_cell] = 3;
+
+    (cave, spawn_cell)
+}
+
+pub fn print_cave(cave: &Cave) {
+    for i in 0..cave.h {
+        for j in 0..cave.w {
+            match cave[(i, j)] {
+                0 => print!("{BG}"),
+                1 => print!("{ROCK}"),
+                2 | 3 => print!("{SAND}"),
+                _ => panic!("unrecognized cave cell"),
+            }
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    // straight segments turning at every point
+    fn trace() -> impl Strategy<Value = Trace> {
+        let start = (0..1000isize, 0..200isize).prop_map(|(x, y)| Point::new(x, y));
+        let steps = prop::collection::vec(-20..20isize, 0..6);
+
+        (start, steps).prop_map(|(start, steps)| {
+            let mut trace = vec![start];
+            for (i, step) in steps.into_iter().enumerate() {
+                let last = *trace.last().unwrap();
+                let next = match i % 2 {
+                    0 => Point::new((last.x + step).max(0), last.y),
+                    _ => Point::new(last.x, (last.y + step).max(0)),
+                };
+                trace.push(next);
+            }
+            trace
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(expected in prop::collection::vec(trace(), 1..10)) {
+            let text: Vec<String> = expected
+                .iter()
+                .map(|trace| {
+                    let points: Vec<String> = trace.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+                    points.join(" -> ")
+                })
+                .collect();
+
+            let (traces, max_y) = parse(&split_lines(&text.join("\n"))).unwrap();
+
+            prop_assert_eq!(max_y as isize, expected.iter().flatten().map(|p| p.y).max().unwrap());
+            prop_assert_eq!(traces, expected);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "([0-9]{1,3},[0-9]{1,3}( -> |\n))*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+    }
+}
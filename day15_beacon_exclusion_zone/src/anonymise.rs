@@ -0,0 +1,121 @@
+use std::{ops::RangeInclusive, process};
+
+use geometry::Point;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{compute_impossible_positions, find_possible_position_in, parse, Input};
+
+const MAX_OFFSET: isize = 1_000_000;
+
+// All sensors and beacons are shifted by the same offset and the row of
+// interest with them. Distances are kept, so part 1 counts the same positions
+// on the shifted row, and the distress beacon is the one found in the search
+// square shifted the same way.
+pub fn run(lines: &[String], seed: Option<u64>) {
+    let seed = seed.unwrap_or_else(rand::random);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let input = parse(lines).unwrap_or_else(|err| err.exit());
+    let (anonymised, offset) = anonymise(&input, &mut rng);
+
+    let (count, beacon) = answers(&input, Point::ORIGIN);
+    let expected = (count, beacon.map(|p| p + offset));
+    let actual = answers(&parse(&anonymised).unwrap_or_else(|err| err.exit()), offset);
+
+    if expected != actual {
+        eprintln!("anonymisation changed the answers: expected {expected:?}, got {actual:?}");
+        process::exit(1);
+    }
+
+    for line in anonymised {
+        println!("{line}");
+    }
+    eprintln!("seed {seed}: answers preserved up to the translation");
+}
+
+// the anonymised lines and the offset they were shifted by
+fn anonymise(input: &Input, rng: &mut StdRng) -> (Vec<String>, Point) {
+    let (y_line, search_max, coordinates) = input;
+    let points = || {
+        coordinates
+            .iter()
+            .flat_map(|&(sensor, beacon)| [sensor, beacon])
+    };
+
+    let offset = Point::new(
+        rng.gen_range(offsets(points().map(|p| p.x))),
+        rng.gen_range(offsets(points().map(|p| p.y).chain([*y_line]))),
+    );
+
+    let mut coordinates = coordinates.clone();
+    coordinates.shuffle(rng);
+
+    let mut result = vec![(y_line + offset.y).to_string(), search_max.to_string()];
+    for (sensor, beacon) in coordinates {
+        let (sensor, beacon) = (sensor + offset, beacon + offset);
+        result.push(format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.x, sensor.y, beacon.x, beacon.y
+        ));
+    }
+
+    (result, offset)
+}
+
+// the offsets that keep every value within the i32 range the parser accepts;
+// the values are in that range already, so 0 is always one of them
+fn offsets(values: impl Iterator<Item = isize>) -> RangeInclusive<isize> {
+    let (min, max) = values.fold((-MAX_OFFSET, MAX_OFFSET), |(min, max), n| {
+        (
+            min.max(i32::MIN as isize - n),
+            max.min(i32::MAX as isize - n),
+        )
+    });
+
+    min..=max
+}
+
+// part 1 and the distress beacon in the search square starting at `corner`
+fn answers(input: &Input, corner: Point) -> (isize, Option<Point>) {
+    let (y_line, search_max, coordinates) = input;
+
+    (
+        compute_impossible_positions(coordinates, *y_line),
+        find_possible_position_in(coordinates, corner, *search_max),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+
+    fn assert_preserved(text: &str) {
+        let input = parse(&split_lines(text)).unwrap();
+        let (count, beacon) = answers(&input, Point::ORIGIN);
+
+        for seed in 0..20 {
+            let (anonymised, offset) = anonymise(&input, &mut StdRng::seed_from_u64(seed));
+            let translated = parse(&anonymised).unwrap();
+            assert_eq!(
+                answers(&translated, offset),
+                (count, beacon.map(|p| p + offset))
+            );
+        }
+    }
+
+    #[test]
+    fn example_answers_are_preserved() {
+        assert_preserved(include_str!("../example.txt"));
+    }
+
+    #[test]
+    fn coordinates_at_the_i32_limits_stay_parseable() {
+        assert_preserved(
+            "-2147483647\n\
+             3\n\
+             Sensor at x=2147483640, y=-2147483648: closest beacon is at x=2147483647, y=-2147483645\n\
+             Sensor at x=2147483647, y=-2147483640: closest beacon is at x=2147483645, y=-2147483641",
+        );
+    }
+}
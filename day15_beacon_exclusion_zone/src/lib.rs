@@ -0,0 +1,196 @@
+pub mod anonymise;
+
+use common::{Answer, ParseError};
+use geometry::Point;
+use std::collections::HashSet;
+
+pub type SBPair = (Point, Point);
+
+// the row checked in part 1, the size of the part 2 search square and the
+// sensor/beacon pairs
+pub type Input = (isize, isize, Vec<SBPair>);
+
+pub fn solve(lines: &[String]) -> Result<(Answer, Answer), ParseError> {
+    let (y_line, search_max, coordinates) = parse(lines)?;
+
+    let position_count = compute_impossible_positions(&coordinates, y_line);
+    let pos = find_possible_position(&coordinates, search_max)
+        .ok_or_else(|| ParseError::new("every position of the search square is ruled out"))?;
+
+    Ok((position_count.into(), encode(&pos).into()))
+}
+
+pub fn encode(pos: &Point) -> u64 {
+    pos.x as u64 * 4000000 + pos.y as u64
+}
+
+pub fn parse(lines: &[String]) -> Result<Input, ParseError> {
+    let header = |i: usize, name: &str| {
+        lines
+            .get(i)
+            .and_then(|line| line.parse::<i32>().ok())
+            .map(|n| n as isize)
+            .ok_or_else(|| ParseError::at(i, format!("expected the {name}")))
+    };
+
+    let y_line = header(0, "row to check")?;
+    let search_max = header(1, "search size")?;
+    let coordinates = parse_coordinates(&lines[2..]).map_err(|err| err.offset(2))?;
+
+    Ok((y_line, search_max, coordinates))
+}
+
+pub fn parse_coordinates(lines: &[String]) -> Result<Vec<SBPair>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            parse_pair(line).ok_or_else(|| ParseError::at(i, format!("invalid report: {line:?}")))
+        })
+        .collect()
+}
+
+// coordinates are kept within i32 so distances between them cannot overflow
+fn parse_pair(line: &str) -> Option<SBPair> {
+    let rest = line.strip_prefix("Sensor at x=")?;
+    let (sx, rest) = rest.split_once(", y=")?;
+    let (sy, rest) = rest.split_once(": closest beacon is at x=")?;
+    let (bx, by) = rest.split_once(", y=")?;
+
+    let coordinate = |s: &str| s.parse::<i32>().ok().map(|n| n as isize);
+
+    Some((
+        Point::new(coordinate(sx)?, coordinate(sy)?),
+        Point::new(coordinate(bx)?, coordinate(by)?),
+    ))
+}
+
+pub fn compute_impossible_positions(coordinates: &[SBPair], y_line: isize) -> isize {
+    let mut x_min = isize::MAX;
+    let mut x_max = isize::MIN;
+
+    let mut occupied_positions = HashSet::<Point>::new();
+
+    for (sensor, beacon) in coordinates {
+        let dist = sensor.manhattan(beacon);
+        if (sensor.y - y_line).abs() <= dist {
+            let delta = dist - (sensor.y - y_line).abs();
+
+            x_min = x_min.min(sensor.x - delta);
+            x_max = x_max.max(sensor.x + delta);
+        }
+
+        if sensor.y == y_line {
+            occupied_positions.insert(*sensor);
+        }
+        if beacon.y == y_line {
+            occupied_positions.insert(*beacon);
+        }
+    }
+
+    // no sensor reaches the row, so nothing on it is ruled out
+    if x_min > x_max {
+        return 0;
+    }
+
+    let mut possible_positions = 0;
+
+    let mut x = x_min;
+    'walk: while x <= x_max {
+        for (sensor, beacon) in coordinates.iter() {
+            let sensor_to_point = sensor.manhattan(&Point::new(x, y_line));
+            let sensor_to_beacon = sensor.manhattan(beacon);
+            if sensor_to_point <= sensor_to_beacon {
+                let jump = sensor_to_beacon - (y_line - sensor.y).abs() + sensor.x - x + 1;
+                x += jump;
+                continue 'walk;
+            }
+        }
+
+        possible_positions += 1;
+        x += 1;
+    }
+
+    x_max - x_min + 1 - possible_positions - occupied_positions.len() as isize
+}
+
+// the first position of the search square that no sensor rules out, row by row
+pub fn find_possible_position(coordinates: &[SBPair], search_max: isize) -> Option<Point> {
+    find_possible_position_in(coordinates, Point::ORIGIN, search_max)
+}
+
+// as `find_possible_position`, for the square from `corner` to `corner` plus
+// `search_max` on both axes
+pub fn find_possible_position_in(
+    coordinates: &[SBPair],
+    corner: Point,
+    search_max: isize,
+) -> Option<Point> {
+    for y in corner.y..=corner.y + search_max {
+        let mut x = corner.x;
+        'x_walk: while x <= corner.x + search_max {
+            for (sensor, beacon) in coordinates.iter() {
+                let sensor_to_point = sensor.manhattan(&Point::new(x, y));
+                let sensor_to_beacon = sensor.manhattan(beacon);
+                if sensor_to_point <= sensor_to_beacon {
+                    let jump = sensor_to_beacon - (y - sensor.y).abs() + sensor.x - x + 1;
+                    x += jump;
+                    continue 'x_walk;
+                }
+            }
+
+            return Some(Point::new(x, y));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::split_lines;
+    use proptest::prelude::*;
+
+    fn point() -> impl Strategy<Value = Point> {
+        (any::<i32>(), any::<i32>()).prop_map(|(x, y)| Point::new(x as isize, y as isize))
+    }
+
+    #[test]
+    fn rows_no_sensor_reaches_rule_nothing_out() {
+        let text = "0\n0\nSensor at x=0, y=10: closest beacon is at x=1, y=10";
+
+        assert_eq!(solve(&split_lines("0\n0")).unwrap().0, Answer::from(0));
+        assert_eq!(solve(&split_lines(text)).unwrap().0, Answer::from(0));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(
+            y_line in any::<i32>(),
+            search_max in any::<i32>(),
+            coordinates in prop::collection::vec((point(), point()), 0..20),
+        ) {
+            let mut text = vec![y_line.to_string(), search_max.to_string()];
+            for (s, b) in &coordinates {
+                text.push(format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    s.x, s.y, b.x, b.y
+                ));
+            }
+
+            let expected = (y_line as isize, search_max as isize, coordinates);
+            prop_assert_eq!(parse(&split_lines(&text.join("\n"))).unwrap(), expected);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "(-?[0-9]+\n){0,2}(Sensor at x=-?[0-9]+, y=-?[0-9]+: closest beacon is at x=-?[0-9]+, y=-?[0-9]+\n)*|\\PC*") {
+            let _ = parse(&split_lines(&text));
+        }
+
+        #[test]
+        fn solve_never_panics(text in "(-?[0-9]{1,3}\n){2}(Sensor at x=-?[0-9]{1,3}, y=-?[0-9]{1,3}: closest beacon is at x=-?[0-9]{1,3}, y=-?[0-9]{1,3}\n){0,8}") {
+            let _ = solve(&split_lines(&text));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let lines = common::split_lines(data);
+    let _ = day05_supply_stacks::parse(&lines);
+    let _ = day05_supply_stacks::solve(&lines);
+});
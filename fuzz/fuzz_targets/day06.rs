@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let lines = common::split_lines(data);
+    let _ = day06_tuning_trouble::parse(&lines);
+    let _ = day06_tuning_trouble::solve(&lines);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let lines = common::split_lines(data);
+    let _ = day07_no_space_left_on_device::parse(&lines);
+    let _ = day07_no_space_left_on_device::solve(&lines);
+});
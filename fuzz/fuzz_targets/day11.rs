@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let lines = common::split_lines(data);
+    let _ = day11_monkey_in_the_middle::parse(&lines);
+    let _ = day11_monkey_in_the_middle::solve(&lines);
+});
@@ -0,0 +1,129 @@
+use crate::Point;
+
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
+pub enum Direction {
+    U,
+    R,
+    D,
+    L,
+}
+
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
+pub enum Direction8 {
+    U,
+    UR,
+    R,
+    DR,
+    D,
+    DL,
+    L,
+    UL,
+}
+
+impl Direction {
+    // clockwise, starting from up
+    pub const ALL: [Direction; 4] = [Direction::U, Direction::R, Direction::D, Direction::L];
+
+    pub fn step(&self) -> Point {
+        match self {
+            Direction::U => Point::new(0, -1),
+            Direction::R => Point::new(1, 0),
+            Direction::D => Point::new(0, 1),
+            Direction::L => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        Direction::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        Direction::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn reverse(&self) -> Direction {
+        Direction::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+impl Direction8 {
+    // clockwise, starting from up
+    pub const ALL: [Direction8; 8] = [
+        Direction8::U,
+        Direction8::UR,
+        Direction8::R,
+        Direction8::DR,
+        Direction8::D,
+        Direction8::DL,
+        Direction8::L,
+        Direction8::UL,
+    ];
+
+    pub fn step(&self) -> Point {
+        match self {
+            Direction8::U => Point::new(0, -1),
+            Direction8::UR => Point::new(1, -1),
+            Direction8::R => Point::new(1, 0),
+            Direction8::DR => Point::new(1, 1),
+            Direction8::D => Point::new(0, 1),
+            Direction8::DL => Point::new(-1, 1),
+            Direction8::L => Point::new(-1, 0),
+            Direction8::UL => Point::new(-1, -1),
+        }
+    }
+
+    // turns are in steps of 45 degrees
+    pub fn turn_right(&self) -> Direction8 {
+        Direction8::ALL[(*self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(&self) -> Direction8 {
+        Direction8::ALL[(*self as usize + 7) % 8]
+    }
+
+    pub fn reverse(&self) -> Direction8 {
+        Direction8::ALL[(*self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Direction8 {
+        match dir {
+            Direction::U => Direction8::U,
+            Direction::R => Direction8::R,
+            Direction::D => Direction8::D,
+            Direction::L => Direction8::L,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_go_around() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.reverse().step(), -dir.step());
+            assert_eq!(Direction8::from(dir).step(), dir.step());
+        }
+        for dir in Direction8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.reverse().step(), -dir.step());
+        }
+        assert_eq!(Direction::L.turn_right(), Direction::U);
+        assert_eq!(Direction8::UL.turn_right(), Direction8::U);
+    }
+
+    #[test]
+    fn steps_are_distinct_and_clockwise() {
+        let steps: Vec<Point> = Direction8::ALL.iter().map(Direction8::step).collect();
+
+        for (i, step) in steps.iter().enumerate() {
+            assert_eq!(step.chebyshev(&Point::ORIGIN), 1);
+            assert_eq!(steps[(i + 2) % 8], step.rotate_right());
+        }
+    }
+}
@@ -0,0 +1,404 @@
+use std::fmt::Debug;
+
+// Integers an interval can be made of.
+pub trait Section: Copy + Ord + Debug {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn succ(self) -> Option<Self>;
+    fn pred(self) -> Option<Self>;
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! section {
+    ($($t:ty),*) => {
+        $(impl Section for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+        })*
+    };
+}
+
+section!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+// Both ends are inclusive, so an interval can reach `T::MAX`. Intervals with
+// `end < start` are empty, and all empty intervals are equal.
+#[derive(Debug, Copy, Clone)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Section> Interval<T> {
+    pub fn closed(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    // from `start` up to but not including `end`
+    pub fn half_open(start: T, end: T) -> Self {
+        match end.pred() {
+            Some(end) if start <= end => Interval { start, end },
+            _ => Interval::empty(),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Interval {
+            start: T::MAX,
+            end: T::MIN,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    // one past the last section, None when the interval reaches `T::MAX`
+    pub fn end_exclusive(&self) -> Option<T> {
+        self.end.succ()
+    }
+
+    // the number of sections, which for 64-bit types can be 2^64
+    pub fn len(&self) -> u128 {
+        match self.is_empty() {
+            true => 0,
+            false => (self.end.to_i128() - self.start.to_i128()) as u128 + 1,
+        }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    // every section of `other` is in this interval
+    pub fn covers(&self, other: &Interval<T>) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    // no section between the two, so their union is an interval
+    pub fn touches(&self, other: &Interval<T>) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return true;
+        }
+        let (first, second) = match self.start <= other.start {
+            true => (self, other),
+            false => (other, self),
+        };
+        first.end.succ().is_none_or(|next| next >= second.start)
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Interval<T> {
+        Interval::closed(self.start.max(other.start), self.end.min(other.end)).normalised()
+    }
+
+    // the smallest interval covering both
+    pub fn hull(&self, other: &Interval<T>) -> Interval<T> {
+        match (self.is_empty(), other.is_empty()) {
+            (true, _) => *other,
+            (_, true) => *self,
+            _ => Interval::closed(self.start.min(other.start), self.end.max(other.end)),
+        }
+    }
+
+    // None when there is a gap between the two
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.touches(other).then(|| self.hull(other))
+    }
+
+    // the sections of this interval that are not in `other`
+    pub fn difference(&self, other: &Interval<T>) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.insert(*self);
+        set.remove(*other);
+        set
+    }
+
+    fn normalised(self) -> Self {
+        match self.is_empty() {
+            true => Interval::empty(),
+            false => self,
+        }
+    }
+}
+
+impl<T: Section> PartialEq for Interval<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => true,
+            (false, false) => self.start == other.start && self.end == other.end,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Section> Eq for Interval<T> {}
+
+// Disjoint intervals with gaps between them, from lowest to highest.
+#[derive(Debug, Clone)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Section> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // the number of sections in all the intervals
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end < x);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(x))
+    }
+
+    // adds the interval, merging it with the ones it overlaps or touches
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .partition_point(|i| i.end < interval.start && !i.touches(&interval));
+        let last = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end || i.touches(&interval));
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| merged.hull(i));
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let mut kept = Vec::with_capacity(self.intervals.len() + 1);
+        for &i in &self.intervals {
+            if !i.overlaps(&interval) {
+                kept.push(i);
+                continue;
+            }
+            if let Some(end) = interval.start.pred().filter(|&end| i.start <= end) {
+                kept.push(Interval::closed(i.start, end));
+            }
+            if let Some(start) = interval.end.succ().filter(|&start| start <= i.end) {
+                kept.push(Interval::closed(start, i.end));
+            }
+        }
+        self.intervals = kept;
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut set = self.clone();
+        for &i in &other.intervals {
+            set.insert(i);
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let (a, b) = (&self.intervals, &other.intervals);
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = Vec::new();
+
+        while i < a.len() && j < b.len() {
+            let common = a[i].intersection(&b[j]);
+            if !common.is_empty() {
+                intervals.push(common);
+            }
+            match a[i].end < b[j].end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut set = self.clone();
+        for &i in &other.intervals {
+            set.remove(i);
+        }
+        set
+    }
+
+    // the sections of `bounds` that are in none of the intervals
+    pub fn gaps(&self, bounds: Interval<T>) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.insert(bounds);
+        set.difference(self)
+    }
+}
+
+impl<T: Section> PartialEq for IntervalSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.intervals == other.intervals
+    }
+}
+
+impl<T: Section> Eq for IntervalSet<T> {}
+
+impl<T: Section> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Section> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn empty_intervals_are_all_equal() {
+        assert!(Interval::closed(5u8, 4).is_empty());
+        assert_eq!(Interval::closed(5u8, 4), Interval::empty());
+        assert_eq!(Interval::closed(9u8, 0).len(), 0);
+        assert!(!Interval::<u8>::empty().contains(0));
+        assert!(Interval::closed(3u8, 3).covers(&Interval::empty()));
+    }
+
+    #[test]
+    fn half_open_intervals_leave_out_their_end() {
+        assert_eq!(Interval::half_open(2u8, 5), Interval::closed(2, 4));
+        assert!(Interval::half_open(5u8, 5).is_empty());
+        assert!(Interval::half_open(0u8, 0).is_empty());
+        assert_eq!(Interval::closed(2u8, 4).end_exclusive(), Some(5));
+        assert_eq!(Interval::closed(2u8, u8::MAX).end_exclusive(), None);
+    }
+
+    #[test]
+    fn len_counts_every_section() {
+        assert_eq!(Interval::closed(3u8, 3).len(), 1);
+        assert_eq!(Interval::closed(u8::MIN, u8::MAX).len(), 256);
+        assert_eq!(Interval::closed(i64::MIN, i64::MAX).len(), 1 << 64);
+        assert_eq!(Interval::closed(u64::MIN, u64::MAX).len(), 1 << 64);
+    }
+
+    #[test]
+    fn difference_splits_around_the_removed_sections() {
+        let difference = |a: Interval<u8>, b: Interval<u8>| a.difference(&b).intervals().to_vec();
+
+        assert_eq!(
+            difference(Interval::closed(0, 9), Interval::closed(3, 5)),
+            [Interval::closed(0, 2), Interval::closed(6, 9)]
+        );
+        assert_eq!(
+            difference(Interval::closed(0, 9), Interval::closed(5, 20)),
+            [Interval::closed(0, 4)]
+        );
+        assert_eq!(
+            difference(Interval::closed(0, 9), Interval::empty()),
+            [Interval::closed(0, 9)]
+        );
+        assert_eq!(
+            difference(Interval::closed(3, 5), Interval::closed(0, 9)),
+            []
+        );
+        assert_eq!(
+            difference(
+                Interval::closed(0, u8::MAX),
+                Interval::closed(u8::MAX, u8::MAX)
+            ),
+            [Interval::closed(0, u8::MAX - 1)]
+        );
+    }
+
+    fn small() -> impl Strategy<Value = Interval<u8>> {
+        (any::<u8>(), any::<u8>()).prop_map(|(a, b)| Interval::closed(a, b))
+    }
+
+    fn sections(set: &IntervalSet<u8>) -> BTreeSet<u8> {
+        (0..=u8::MAX).filter(|&x| set.contains(x)).collect()
+    }
+
+    fn model(intervals: &[Interval<u8>]) -> BTreeSet<u8> {
+        intervals
+            .iter()
+            .flat_map(|i| (0..=u8::MAX).filter(|&x| i.contains(x)))
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn interval_sets_match_section_sets(
+            a in prop::collection::vec(small(), 0..6),
+            b in prop::collection::vec(small(), 0..6),
+        ) {
+            let (set_a, set_b): (IntervalSet<u8>, IntervalSet<u8>) =
+                (a.iter().copied().collect(), b.iter().copied().collect());
+            let (model_a, model_b) = (model(&a), model(&b));
+
+            prop_assert_eq!(set_a.len(), model_a.len() as u128);
+            prop_assert!(set_a.intervals().windows(2).all(|w| !w[0].touches(&w[1])));
+            prop_assert_eq!(sections(&set_a.union(&set_b)), &model_a | &model_b);
+            prop_assert_eq!(sections(&set_a.intersection(&set_b)), &model_a & &model_b);
+            prop_assert_eq!(sections(&set_a.difference(&set_b)), &model_a - &model_b);
+            prop_assert_eq!(
+                sections(&set_a.gaps(Interval::closed(0, u8::MAX))).len() + model_a.len(),
+                256
+            );
+        }
+
+        #[test]
+        fn intervals_match_section_sets(a in small(), b in small()) {
+            let (model_a, model_b) = (model(&[a]), model(&[b]));
+
+            prop_assert_eq!(a.len(), model_a.len() as u128);
+            prop_assert_eq!(model(&[a.intersection(&b)]), &model_a & &model_b);
+            prop_assert_eq!(sections(&a.difference(&b)), &model_a - &model_b);
+            prop_assert_eq!(a.covers(&b), model_b.is_subset(&model_a));
+            prop_assert_eq!(a.overlaps(&b), !model_a.is_disjoint(&model_b));
+            match a.union(&b) {
+                Some(union) => prop_assert_eq!(model(&[union]), &model_a | &model_b),
+                None => prop_assert!(!a.touches(&b)),
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod direction;
+mod interval;
+mod point;
+mod rect;
+
+pub use direction::{Direction, Direction8};
+pub use interval::{Interval, IntervalSet, Section};
+pub use point::Point;
+pub use rect::Rect;
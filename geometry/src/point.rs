@@ -0,0 +1,171 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{Direction, Direction8};
+
+// x grows to the right and y grows downwards, so y is the row of a grid.
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone, Default, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Point) -> isize {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn signum(&self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn abs(&self) -> Point {
+        Point::new(self.x.abs(), self.y.abs())
+    }
+
+    // quarter turns about the origin, as seen on screen
+    pub fn rotate_left(&self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+
+    pub fn rotate_right(&self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    pub fn neighbors(&self) -> [Point; 4] {
+        Direction::ALL.map(|dir| *self + dir)
+    }
+
+    pub fn neighbors8(&self) -> [Point; 8] {
+        Direction8::ALL.map(|dir| *self + dir)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Direction) -> Point {
+        self + dir.step()
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Direction8) -> Point {
+        self + dir.step()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+impl AddAssign<Direction8> for Point {
+    fn add_assign(&mut self, dir: Direction8) {
+        *self = *self + dir;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, k: isize) -> Point {
+        Point::new(self.x * k, self.y * k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point::new(1, -2), Point::new(-3, 4));
+
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+        assert_eq!((a - b).abs(), Point::new(4, 6));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut p = Point::new(2, 3);
+        p += Point::new(1, 1);
+        p -= Point::new(0, 2);
+
+        assert_eq!(p, Point::new(3, 2));
+        assert_eq!(-p * 2, Point::new(-6, -4));
+        assert_eq!(p + Direction::U, Point::new(3, 1));
+        assert_eq!(p + Direction8::DL, Point::new(2, 3));
+    }
+
+    #[test]
+    fn rotations_match_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.step().rotate_right(), dir.turn_right().step());
+            assert_eq!(dir.step().rotate_left(), dir.turn_left().step());
+        }
+        assert_eq!(
+            Point::new(2, 5).rotate_left().rotate_right(),
+            Point::new(2, 5)
+        );
+    }
+
+    #[test]
+    fn neighbors_are_one_step_away() {
+        let p = Point::new(4, -1);
+
+        assert!(p.neighbors().iter().all(|n| n.manhattan(&p) == 1));
+        assert!(p.neighbors8().iter().all(|n| n.chebyshev(&p) == 1));
+        assert_eq!(p.neighbors()[0], Point::new(4, -2));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::Point;
+
+// Both corners are inclusive.
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    pub fn new(a: Point, b: Point) -> Rect {
+        Rect {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    // `w` by `h` points from the origin. A rect always holds its corners, so
+    // there is none with no width or height.
+    pub fn from_size(w: usize, h: usize) -> Option<Rect> {
+        (w > 0 && h > 0)
+            .then(|| Rect::new(Point::ORIGIN, Point::new(w as isize - 1, h as isize - 1)))
+    }
+
+    pub fn bounding(points: impl IntoIterator<Item = Point>) -> Option<Rect> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        let mut rect = Rect::new(first, first);
+        for p in points {
+            rect.include(p);
+        }
+
+        Some(rect)
+    }
+
+    pub fn include(&mut self, p: Point) {
+        self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn union(&self, other: &Rect) -> Rect {
+        let mut rect = *self;
+        rect.include(other.min);
+        rect.include(other.max);
+        rect
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+
+        match min.x <= max.x && min.y <= max.y {
+            true => Some(Rect { min, max }),
+            false => None,
+        }
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    // row by row, top to bottom
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Rect { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn sizes_count_points() {
+        let rect = Rect::from_size(3, 2).unwrap();
+
+        assert_eq!(rect, Rect::new(Point::new(2, 1), Point::ORIGIN));
+        assert_eq!((rect.width(), rect.height(), rect.area()), (3, 2, 6));
+        assert_eq!(rect.points().count(), 6);
+        assert_eq!(rect.points().nth(3), Some(Point::new(0, 1)));
+        assert_eq!(Rect::from_size(0, 2), None);
+        assert_eq!(Rect::from_size(2, 0), None);
+    }
+
+    #[test]
+    fn intersections_and_unions() {
+        let a = Rect::new(Point::new(0, 0), Point::new(3, 3));
+        let b = Rect::new(Point::new(2, 3), Point::new(6, 5));
+
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::new(Point::new(2, 3), Point::new(3, 3)))
+        );
+        assert_eq!(a.union(&b), Rect::new(Point::new(0, 0), Point::new(6, 5)));
+        assert_eq!(
+            a.intersection(&Rect::new(Point::new(4, 0), Point::new(5, 3))),
+            None
+        );
+        assert_eq!(Rect::bounding([]), None);
+    }
+
+    fn point() -> impl Strategy<Value = Point> {
+        (-20..20isize, -20..20isize).prop_map(|(x, y)| Point::new(x, y))
+    }
+
+    proptest! {
+        #[test]
+        fn bounding_rects_are_the_smallest(points in prop::collection::vec(point(), 1..10)) {
+            let rect = Rect::bounding(points.iter().copied()).unwrap();
+
+            prop_assert!(points.iter().all(|p| rect.contains(p)));
+            for corner in [rect.min, rect.max] {
+                prop_assert!(points.iter().any(|p| p.x == corner.x));
+                prop_assert!(points.iter().any(|p| p.y == corner.y));
+            }
+        }
+
+        #[test]
+        fn intersections_hold_the_common_points(a in point(), b in point(), c in point(), d in point()) {
+            let (first, second) = (Rect::new(a, b), Rect::new(c, d));
+            let common: Vec<Point> = first.points().filter(|p| second.contains(p)).collect();
+
+            match first.intersection(&second) {
+                Some(rect) => prop_assert_eq!(rect.points().collect::<Vec<_>>(), common),
+                None => prop_assert!(common.is_empty()),
+            }
+        }
+    }
+}
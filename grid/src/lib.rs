@@ -1,11 +1,10 @@
-#![allow(dead_code)]
-
 use std::{
     fmt::{Debug, Display},
     ops::{Index, IndexMut},
 };
 
-use crate::cell::Cell;
+use common::ParseError;
+use geometry::Point;
 
 pub struct Grid<T> {
     pub v: Vec<T>,
@@ -15,25 +14,28 @@ pub struct Grid<T> {
 }
 
 impl Grid<u8> {
-    pub fn parse(lines: &Vec<String>) -> Grid<u8> {
+    pub fn parse(lines: &[String]) -> Result<Grid<u8>, ParseError> {
         let mut v: Vec<u8> = Vec::new();
-        let mut w = 0usize;
-        let mut h = 0usize;
-
-        for line in lines {
-            let mut line = line.as_bytes().to_vec();
-            w = line.len();
-            h += 1;
+        let w = lines.first().map_or(0, String::len);
+        let h = lines.len();
+
+        for (i, line) in lines.iter().enumerate() {
+            if line.len() != w {
+                return Err(ParseError::at(
+                    i,
+                    format!("expected {w} columns, got {}", line.len()),
+                ));
+            }
 
-            v.append(&mut line);
+            v.extend_from_slice(line.as_bytes());
         }
 
-        Grid {
+        Ok(Grid {
             v,
             w,
             h,
             size: w * h,
-        }
+        })
     }
 }
 
@@ -47,7 +49,7 @@ impl<T: Default + Clone + Display + Debug + Eq + Copy> Grid<T> {
         }
     }
 
-    fn print(&self) {
+    pub fn print(&self) {
         for i in 0..self.h {
             for j in 0..self.w {
                 print!("{:?} ", self[(i, j)]);
@@ -56,12 +58,12 @@ impl<T: Default + Clone + Display + Debug + Eq + Copy> Grid<T> {
         }
     }
 
-    pub fn find(&self, el: T) -> Option<Cell> {
+    pub fn find(&self, el: T) -> Option<Point> {
         for ind in 0..self.h * self.w {
             if self.v[ind] == el {
-                let i = (ind / self.w) as isize;
-                let j = (ind % self.w) as isize;
-                return Some(Cell { i, j });
+                let x = (ind % self.w) as isize;
+                let y = (ind / self.w) as isize;
+                return Some(Point { x, y });
             }
         }
 
@@ -83,11 +85,11 @@ impl<T> Index<(usize, usize)> for Grid<T> {
     }
 }
 
-impl<T> Index<Cell> for Grid<T> {
+impl<T> Index<Point> for Grid<T> {
     type Output = T;
 
-    fn index(&self, Cell { i, j }: Cell) -> &T {
-        &self.v[i as usize * self.w + j as usize]
+    fn index(&self, Point { x, y }: Point) -> &T {
+        &self.v[y as usize * self.w + x as usize]
     }
 }
 
@@ -97,8 +99,8 @@ impl<T> IndexMut<(usize, usize)> for Grid<T> {
     }
 }
 
-impl<T> IndexMut<Cell> for Grid<T> {
-    fn index_mut(&mut self, Cell { i, j }: Cell) -> &mut T {
-        &mut self.v[i as usize * self.w + j as usize]
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, Point { x, y }: Point) -> &mut T {
+        &mut self.v[y as usize * self.w + x as usize]
     }
 }